@@ -4,6 +4,8 @@ const HOST_FAMILY: &str = "windows";
 #[cfg(unix)]
 const HOST_FAMILY: &str = "unix";
 
+// The embedded example animation isn't generated here: a build script can't depend on the
+// crate it builds, so examples/embed.rs writes examples/assets/animation.rs instead.
 fn main ()
 {
     #[cfg(any(windows, unix))] {
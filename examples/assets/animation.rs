@@ -0,0 +1,2503 @@
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
+pub static ANIMATION: ::melee_anim_rs::embed::StaticAnimation = ::melee_anim_rs::embed::StaticAnimation {
+    frame_count: 110.0,
+    root_bone_index: 0,
+    bones: &[
+        ::melee_anim_rs::embed::StaticBone {
+            index: 0,
+            parent: -1,
+            name: "JOBJ_0",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 1,
+            parent: 0,
+            name: "JOBJ_1",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.375, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, -0.099975586, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -6.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, -6.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, -6.0, 0.11999512, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.24993896, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 2,
+            parent: 1,
+            name: "JOBJ_2",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.0, ty: 8.300001, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 3,
+            parent: 2,
+            name: "JOBJ_3",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.0, ty: 0.0, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.05444336, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, -0.020690918, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.87145996, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(42.0, -0.87145996, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, -1.020874, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.87145996, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(97.0, -0.11779785, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.008361816, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(15.0, 1.1256714, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(42.0, 1.1256714, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, 1.0117188, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, 1.1256714, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.74542236, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.057556152, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, -0.021911621, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.9213867, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(42.0, -0.9213867, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, -1.0915527, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.9213867, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(97.0, -0.10510254, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.007507324, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.015136719, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.24230957, 0.0057373047, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.24230957, 0.00012207031, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.25048828, -0.00036621094, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.22363281, -0.0390625, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, -1.1953125, -0.00970459, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.08532715, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.92626953, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(5.0, -1.7321777, -0.20855713, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -4.055664, -0.08453369, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, -4.055664, 0.02154541, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -3.6308594, 0.0025634766, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, -3.2106934, 0.08453369, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, -0.50097656, 0.0993042, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.92626953, -0.030334473, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.0028076172, -0.10015869, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -1.6060791, -0.038146973, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, -1.6060791, -0.0040893555, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -1.7923584, 0.004211426, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, -1.4155273, 0.061645508, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.4897461, 0.061401367, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.0028076172, -0.03515625, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 4,
+            parent: 3,
+            name: "JOBJ_4",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.0, ty: 0.0, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.002532959, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.002532959, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.56481934, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.56481934, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.015625, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.015625, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.12332153, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.12332153, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.23831177, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.23831177, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 5,
+            parent: 4,
+            name: "JOBJ_5",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.1095759, ty: -1.0778459, tz: 0.37384498, rx: -1.5707961, ry: 1.1920929e-7, rz: -1.5707961},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 6,
+            parent: 5,
+            name: "JOBJ_6",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.0, ty: -0.0, tz: 0.0, rx: 0.0038519998, ry: -0.020238005, rz: 0.045124},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.53308105, 0.1081543, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(1.0, -0.42492676, 0.1081543, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(3.0, 0.0, 0.18835449, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(3.0, 0.017333984, 0.2539673, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(5.0, 0.0, 0.27441406, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(5.0, 0.5258789, 0.23406982, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(10.0, 0.0, 0.066345215, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(10.0, 0.666626, -0.0038452148, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(11.0, 0.0, -0.8210449, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(11.0, -0.15429688, -0.8210449, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(12.0, 0.0, -0.60491943, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(12.0, -0.75927734, -0.60491943, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(13.0, 0.0, -0.25738525, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(13.0, -1.0166016, -0.25738525, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, -0.122802734, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -1.2059326, -0.06640625, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(36.0, 0.0, -0.0099487305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(36.0, -1.069458, 0.016723633, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.0, 0.00030517578, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(60.0, -1.217041, -0.012084961, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.0, 0.0048217773, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.9897461, 0.026550293, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(89.0, 0.0, 0.05847168, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(89.0, -0.817749, 0.11352539, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(90.0, 0.0, 0.19354248, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(90.0, -0.6241455, 0.19354248, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(91.0, 0.0, 0.30743408, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(91.0, -0.3166504, 0.30743408, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, 0.4192505, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.10253906, 0.4192505, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(95.0, 0.0, 0.05218506, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(95.0, 0.14855957, -0.021911621, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(98.0, 0.0, -0.053588867, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(98.0, -0.018554688, -0.040100098, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.53308105, 0.019592285, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.4317627, -0.0030517578, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(4.0, -0.5373535, -0.0647583, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(11.0, 0.0, -0.10644531, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(11.0, -1.2965088, -0.06072998, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.109375, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.76916504, 0.11621094, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(41.0, 0.0, 0.016662598, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(41.0, -0.7324219, -0.0020751953, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(66.0, -0.8222656, 0.0040283203, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.8671875, -0.014038086, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, -0.05279541, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, -1.133667, -0.0061035156, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.0, 0.08166504, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(97.0, -0.55029297, 0.15716553, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(107.0, 0.0, 0.0446167, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(107.0, -0.40368652, -0.013000488, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.4317627, -0.0032348633, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.39562988, 0.06378174, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(3.0, -0.3083496, -0.0067749023, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(6.0, 0.0, -0.04534912, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(6.0, -0.44091797, -0.013916016, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(10.0, -0.44299316, 0.010009766, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(11.0, 0.0, 0.7843628, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(11.0, 0.3413086, 0.7843628, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(12.0, 0.0, 0.5701294, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(12.0, 0.911499, 0.5701294, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(13.0, 0.0, 0.22003174, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(13.0, 1.1315918, 0.22003174, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.08074951, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 1.2313232, 0.020507813, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(41.0, 1.2191162, -0.0064697266, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(56.0, 1.2429199, 0.0060424805, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(63.0, 1.2563477, -0.0072631836, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(88.0, 0.9394531, -0.024414063, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(89.0, 0.0, -0.078552246, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(89.0, 0.86083984, -0.078552246, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(90.0, 0.0, -0.15710449, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(90.0, 0.70373535, -0.15710449, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(91.0, 0.0, -0.2666626, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(91.0, 0.43701172, -0.2666626, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, -0.36895752, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.068115234, -0.36895752, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, -0.031799316, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.14855957, 0.06530762, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.21557617, 0.06695557, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.04046631, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.39562988, -0.056396484, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 7,
+            parent: 6,
+            name: "JOBJ_7",
+            joint: ::melee_anim_rs::bone::Joint {tx: 3.1733408, ty: -0.0, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.017453},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.8978882, 0.1954956, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(2.0, 1.2199097, 0.12646484, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(3.0, 0.0, 0.033813477, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(3.0, 1.2537842, 0.033813477, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(6.0, 0.0, -0.03845215, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(6.0, 1.1726074, 0.0051879883, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(10.0, 0.0, 0.01727295, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(10.0, 1.2052612, -0.01184082, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(12.0, 0.0, 0.2265625, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(12.0, 1.6027222, 0.1708374, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.12347412, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 1.8405762, 0.03527832, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(44.0, 0.0, 0.025817871, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(44.0, 1.8296509, -0.0053100586, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(68.0, 1.7897339, 0.006225586, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 1.5871582, -0.042541504, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(89.0, 0.0, -0.019042969, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(89.0, 1.4887695, -0.0793457, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(91.0, 0.0, -0.13238525, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(91.0, 1.1734619, -0.18286133, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, -0.23858643, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.9348755, -0.23858643, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, 0.04522705, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, 1.246521, 0.12420654, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.0, 0.16821289, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(97.0, 1.4147339, 0.16821289, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.016784668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.8978882, -0.15875244, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 8,
+            parent: 7,
+            name: "JOBJ_8",
+            joint: ::melee_anim_rs::bone::Joint {tx: 2.6450398, ty: 0.098510996, tz: 0.031499997, rx: 0.0, ry: -2e-6, rz: -1.5991379},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 9,
+            parent: 8,
+            name: "JOBJ_9",
+            joint: ::melee_anim_rs::bone::Joint {tx: 9.999999e-7, ty: -1.9999998e-6, tz: -0.0, rx: -0.018313002, ry: 0.008525, rz: 0.26193905},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.073913574, -0.1954956, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(4.0, -0.6223755, -0.1262207, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(10.0, 0.0, -0.09106445, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(10.0, -0.95513916, -0.032714844, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(13.0, 0.0, 0.001953125, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(13.0, -0.8040161, 0.095825195, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.12768555, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.5299072, 0.14642334, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(68.0, 0.0, -0.009216309, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(68.0, -0.4906006, -0.004333496, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.41723633, 0.020507813, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(90.0, 0.0, 0.11193848, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(90.0, -0.008239746, 0.15771484, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, 0.068603516, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.15216064, 0.09185791, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, 0.018127441, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.091308594, -0.05621338, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(101.0, 0.0, -0.087890625, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(101.0, -0.28076172, -0.076416016, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(103.0, 0.0, -0.06628418, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(103.0, -0.39709473, -0.049987793, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.043640137, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.073913574, 0.09979248, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.037719727, 0.0059814453, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(5.0, 0.24627686, 0.07867432, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(13.0, 0.0, 0.02923584, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(13.0, 0.2296753, -0.05230713, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, -0.06829834, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.08117676, -0.08013916, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(36.0, 0.0, -0.005004883, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(36.0, -0.081726074, -0.01184082, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.0, 0.004699707, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.111572266, 0.009460449, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.0, -0.0022583008, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.053955078, -0.0021972656, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(90.0, 0.0, 0.0881958, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(90.0, 0.32080078, 0.086364746, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, -0.008544922, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.2949829, -0.017211914, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.0, 0.04675293, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.31756592, -0.04034424, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(106.0, 0.08074951, -0.009643555, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.037719727, -0.012817383, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.09326172, -0.043518066, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(5.0, -0.33447266, -0.084350586, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(11.0, 0.0, -0.11395264, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(11.0, -0.9071045, -0.047668457, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(26.0, 0.0, -0.035827637, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(26.0, -1.0948486, -0.0017700195, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, -1.0411377, 0.0026245117, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -1.060791, -0.0059814453, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.0, 0.0056152344, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.87060547, 0.0099487305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(90.0, 0.0, -0.0065307617, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(90.0, -0.78015137, 0.06866455, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, 0.083862305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, -0.6123047, 0.08392334, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, 0.15240479, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, -0.10473633, 0.08862305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(98.0, 0.0, 0.05596924, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(98.0, -0.053588867, -0.0053710938, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.09326172, -0.014770508, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 10,
+            parent: 9,
+            name: "JOBJ_10",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.7927377, ty: 0.33013397, tz: -0.023944998, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.0, -0.0050222217, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(90.0, -0.45196533, 0.019822627, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.19818115, 0.017999997, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(102.0, -0.23596191, -0.015248168, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.026221476, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 11,
+            parent: 4,
+            name: "JOBJ_11",
+            joint: ::melee_anim_rs::bone::Joint {tx: -1.1095839, ty: -1.0778459, tz: 0.37384498, rx: -1.5707961, ry: 1.1920929e-7, rz: -1.5707961},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 12,
+            parent: 11,
+            name: "JOBJ_12",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: -0.0, tz: 0.0, rx: -0.0052089994, ry: 0.019170998, rz: 0.04995},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.018798828, -0.020263672, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(12.0, -0.85095215, -0.043518066, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(17.0, 0.0, -0.024108887, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(17.0, -0.9057617, -0.013183594, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, -0.8748169, 0.0078125, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.0, 0.016784668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.48846436, 0.012573242, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.0, -0.00793457, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.61206055, 0.001159668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(91.0, 0.0, -0.072143555, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(91.0, -0.7137451, 0.024169922, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.0, 0.052612305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(97.0, -0.30419922, 0.043029785, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(104.0, 0.0, 0.07977295, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(104.0, -0.025817871, 0.004333496, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.018798828, 0.005554199, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.21514893, -0.034240723, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(8.0, 0.21270752, 0.048034668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.05871582, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.6335449, 0.064453125, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.0, 0.0021362305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.5473633, -0.006164551, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.0, 0.0037231445, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.6013794, -0.006652832, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(95.0, 0.0, -0.052612305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(95.0, 0.07775879, -0.068847656, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(104.0, 0.0, -0.051574707, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(104.0, -0.011291504, 0.032958984, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.21514893, 0.035705566, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.2935791, 0.018127441, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(10.0, 0.24658203, -0.070495605, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, -0.09246826, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.2890625, -0.105407715, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.0, -0.022277832, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(42.0, -0.2890625, 0.007751465, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.0, 0.02142334, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.09362793, 0.011962891, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.0, -0.007385254, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.03161621, 0.009216309, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(94.0, 0.0, 0.021911621, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(94.0, 0.42718506, 0.048583984, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(105.0, 0.0, 0.026550293, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(105.0, 0.37731934, -0.014282227, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.2935791, -0.017150879, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 13,
+            parent: 12,
+            name: "JOBJ_13",
+            joint: ::melee_anim_rs::bone::Joint {tx: 3.1793237, ty: 0.0, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.017453},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.7926636, 0.0791626, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(5.0, 1.2393188, 0.053894043, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(10.0, 1.3192139, -0.027893066, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, -0.050720215, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.9397583, -0.08502197, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(43.0, 0.0, 0.0045776367, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(43.0, 0.9281616, -0.012817383, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(62.0, 0.60754395, 0.0033569336, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.7130127, 0.010314941, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(91.0, 0.0, 0.11529541, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(91.0, 1.0325928, 0.03289795, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(94.0, 0.0, -0.009887695, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(94.0, 0.88098145, -0.0892334, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.0, -0.11395264, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.57214355, -0.07836914, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(101.0, 0.0, -0.06304932, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(101.0, 0.46936035, 0.014038086, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.035217285, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.7926636, -0.0017700195, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 14,
+            parent: 13,
+            name: "JOBJ_14",
+            joint: ::melee_anim_rs::bone::Joint {tx: 2.6475205, ty: 0.10779199, tz: 0.012015998, rx: 0.0, ry: 1e-6, rz: -1.5991379},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 15,
+            parent: 14,
+            name: "JOBJ_15",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: -0.0, tz: 0.0, rx: 0.020596005, ry: 0.009198002, rz: 0.25315505},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.30517578, 0.016418457, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(5.0, -0.16046143, 0.078552246, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(12.0, 0.0, 0.105285645, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(12.0, 0.73950195, 0.101257324, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.06890869, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.83343506, -0.007019043, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(51.0, 0.0, 0.0056762695, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(51.0, 0.87750244, 0.0068359375, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(81.0, 0.89575195, -0.0059814453, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(89.0, 0.8027344, -0.03515625, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, -0.06317139, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.1973877, -0.061340332, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(102.0, -0.04925537, -0.046081543, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.30517578, -0.008911133, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.18756104, 0.033203125, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(7.0, 0.40631104, 0.027832031, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(13.0, 0.0, 0.006713867, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(13.0, 0.15777588, -0.07183838, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, 0.023406982, -0.003479004, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(57.0, 0.01739502, -0.0005493164, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.059936523, 0.00579834, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(94.0, 0.0, 0.07336426, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(94.0, 0.587677, 0.04559326, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(99.0, 0.0, 0.02722168, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(99.0, 0.58462524, -0.026672363, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.18756104, -0.040527344, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.18463135, 0.007385254, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(6.0, 0.20062256, 0.02911377, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.051879883, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.8825073, 0.07519531, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.0, 0.01171875, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.8825073, -0.00793457, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(64.0, 0.7069702, 0.005554199, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.76379395, -0.008422852, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(93.0, 0.0, -0.048583984, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(93.0, 0.25317383, -0.10888672, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(98.0, 0.0, -0.070007324, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(98.0, -0.00018310547, -0.012329102, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.0032958984, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.18463135, 0.021118164, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 16,
+            parent: 15,
+            name: "JOBJ_16",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.8148376, ty: 0.335038, tz: -0.06591299, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 17,
+            parent: 4,
+            name: "JOBJ_17",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: -0.652462, tz: -0.788646, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.040039063, -0.00041052327, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(9.0, 0.0028076172, 0.022143995, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.8899536, 0.012651678, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, 0.635437, -0.020353584, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.025878906, -0.013245375, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.040039063, -0.00041052327, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.6092529, -0.03253174, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(9.0, 0.2835083, -0.03161621, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, -0.71936035, -0.016967773, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, -0.5657959, 0.017333984, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.06213379, 0.023010254, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.6092529, 0.022766113, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.021886712, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(9.0, 0.21887207, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.0, -0.004746832, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, -0.018432617, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.0, 0.00036213527, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 18,
+            parent: 17,
+            name: "JOBJ_18",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: -0.217788, tz: -3.22098, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.018554688, -0.037719727, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(9.0, -0.39611816, -0.022399902, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(33.0, -0.7581787, 0.016113281, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, 0.40997314, 0.016662598, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.14245605, -0.020141602, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(99.0, -0.3970337, -0.006958008, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.018554688, 0.03149414, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.24121094, 0.06262207, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(9.0, 0.38549805, 0.007080078, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(33.0, -0.006225586, -0.006958008, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, 0.03491211, -0.0017700195, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.10394287, -0.0051879883, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(99.0, -0.17474365, -0.00592041, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.24121094, -0.005493164, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.019749336, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(9.0, -0.19744873, 0.000110688336, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(33.0, 0.0036010742, 0.0039498466, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, 0.0, -7.264609e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.00024414063, -3.999279e-6, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(99.0, -0.00012207031, 1.1745584e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 1.3330928e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 19,
+            parent: 18,
+            name: "JOBJ_19",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.280193, tz: -1.921333, rx: 0.16999999, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.048095703, 0.062805176, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(9.0, 0.67645264, -0.010009766, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(34.0, -0.2923584, 0.0015258789, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(69.0, 0.770813, 0.00061035156, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(99.0, -0.25268555, -0.017578125, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.048095703, 0.025024414, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 20,
+            parent: 19,
+            name: "JOBJ_20",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.235261, tz: -2.972609, rx: 0.13999997, ry: -0.0, rz: 0.0},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 21,
+            parent: 4,
+            name: "JOBJ_21",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.0, ty: 0.0, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.024902344, 0.0022686222, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(11.0, -0.02746582, -0.0115184635, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(36.0, -0.068603516, 0.0006038489, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(58.0, -0.033569336, -9.851511e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(80.0, -0.06427002, -3.382631e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.024902344, 0.0031364423, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.15283203, -0.04852295, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(7.0, -0.17407227, -0.034729004, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(19.0, -0.387146, -0.0034179688, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(58.0, -0.38604736, -0.0017700195, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(72.0, -0.22650146, 0.024841309, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(82.0, -0.1920166, -0.042175293, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(88.0, -0.36956787, 0.002319336, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(101.0, -0.095947266, 0.027282715, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.15283203, 0.02557373, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.13952637, 0.047851563, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(6.0, 0.38900757, 0.008300781, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(18.0, 0.35708618, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(50.0, 0.35766602, -0.003540039, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(61.0, 0.24298096, -0.006713867, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(80.0, 0.18734741, 0.009460449, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(89.0, 0.0, 0.01977539, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(89.0, 0.36297607, -0.0008544922, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.13952637, -0.01159668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 22,
+            parent: 21,
+            name: "JOBJ_22",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.0, ty: 1.132931, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.020751953, 0.0018905178, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(12.0, -0.032104492, -0.008462371, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(39.0, -0.055480957, 0.0006019639, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(65.0, -0.048828125, -0.0015837507, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.020751953, 0.0026137035, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.15283203, -0.04852295, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(7.0, -0.17407227, -0.034729004, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(19.0, -0.387146, -0.0034179688, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(58.0, -0.38604736, -0.0017700195, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(72.0, -0.22650146, 0.024841309, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(82.0, -0.1920166, -0.042175293, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(88.0, -0.36956787, 0.002319336, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(101.0, -0.095947266, 0.027282715, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.15283203, 0.02557373, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.11627197, 0.03989228, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(6.0, 0.3241577, 0.006947155, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(19.0, 0.29763794, 0.00013122727, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(50.0, 0.2980652, -0.0029921448, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(62.0, 0.19683838, -0.0056362143, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(79.0, 0.14819336, 0.005088202, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(89.0, 0.30245972, -0.00075395755, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.11627197, -0.0096644545, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 23,
+            parent: 22,
+            name: "JOBJ_23",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.8, ty: 0.96521, tz: 0.217753, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0028596446, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.04574585, 0.0007625719, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.04574585, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.04574585, -0.00091508613, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.0019064095, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.010236519, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.16375732, -0.0027297388, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.16375732, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.16375732, 0.0032756862, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0068242745, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.016479492, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.2637329, -0.0043945313, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.2637329, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.2637329, 0.0052490234, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.010986328, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.801634, -0.000607349, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, -0.00016195973, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.79191643, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.79191643, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.79191643, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.801634, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.801634, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.1168823, 0.0031433627, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 1.1671753, 0.0008382301, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, 1.1671753, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(87.0, 1.1671753, -0.0015716812, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, -0.002186687, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, 1.1168823, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.1168823, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.2760315, 0.014725841, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.5116272, 0.003926891, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.5116272, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.5116272, -0.0073629203, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, -0.010244063, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.2760315, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.2760315, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 24,
+            parent: 23,
+            name: "JOBJ_24",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.782557, ty: -0.010110999, tz: 0.010752999, rx: -1.570796, ry: 0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.782557, 0.0023344457, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.0006225189, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 1.8199081, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, 1.8199081, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, 1.8199081, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(92.0, 1.782557, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.782557, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.010110855, 0.0020345168, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.00054253783, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.022441413, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.022441413, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.022441413, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(92.0, -0.010110855, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.010110855, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0107421875, 0.03051178, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.0081364745, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.49893188, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.49893188, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.49893188, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0107421875, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0107421875, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 25,
+            parent: 24,
+            name: "JOBJ_25",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: -0.0, tz: -0.0, rx: -0.0007539998, ry: -0.0045979987, rz: 0.10398898},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.41125488, -0.059387207, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(7.0, -0.5933838, -0.008056641, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.8309326, -0.056518555, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(24.0, -0.85705566, -0.013183594, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.0, -0.024597168, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(32.0, -1.1088867, -0.012939453, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(49.0, -0.94104004, 0.017578125, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(61.0, -0.7923584, 0.01159668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(70.0, -0.9107666, -0.017456055, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(83.0, 0.0, -0.008300781, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(83.0, -0.7416992, 0.025939941, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(90.0, -0.59765625, -0.018676758, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, -0.029174805, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, -0.6542969, -0.027404785, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(98.0, 0.0, 0.04949951, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(98.0, -0.27746582, 0.04284668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(107.0, -0.084472656, -0.02722168, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.066589355, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.41125488, -0.14794922, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.130127, -0.009643555, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(10.0, 0.7546387, -0.060791016, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(19.0, 0.4078369, 0.012939453, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(25.0, 0.37878418, -0.038513184, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(31.0, 0.0, -0.053833008, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(31.0, 0.044677734, -0.029418945, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(44.0, 0.119384766, 0.041503906, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(62.0, 0.6323242, 0.0038452148, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(70.0, 0.0, -0.005126953, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(70.0, 0.37646484, -0.037963867, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(82.0, 0.0, -0.023010254, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(82.0, 0.5286865, 0.012939453, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.62231445, 0.028442383, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(93.0, 0.0, -0.061767578, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(93.0, 0.17565918, -0.04095459, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, -0.115600586, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, -0.1862793, -0.103881836, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(97.0, 0.0, -0.0067749023, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(97.0, -0.19311523, -0.0067749023, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(107.0, 0.0, 0.08972168, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(107.0, 1.0212402, 0.09075928, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.067871094, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.130127, 0.0017700195, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.8240967, -0.055725098, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(7.0, -0.9168701, 0.023132324, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, -0.7999878, -0.0146484375, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(25.0, 0.0, -0.0041503906, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(25.0, -0.7819214, -0.015625, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(44.0, -0.86260986, 0.0022583008, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(61.0, -0.73376465, 0.005554199, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(73.0, -0.9030762, -0.0017700195, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(86.0, -0.70861816, 0.028137207, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.6821289, 0.026489258, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, 0.10534668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, -0.13415527, 0.122680664, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, 0.013000488, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, -0.17956543, -0.036621094, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(105.0, 0.0, -0.022827148, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(105.0, -0.25952148, -0.0146484375, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(108.0, 0.0, -0.034973145, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(108.0, -0.47045898, -0.10949707, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.15795898, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.8239746, -0.1954956, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 26,
+            parent: 25,
+            name: "JOBJ_26",
+            joint: ::melee_anim_rs::bone::Joint {tx: 2.0127068, ty: 0.0, tz: -0.0, rx: 0.0, ry: 0.0, rz: -0.112456},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.6861572, -0.04888916, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(6.0, -1.0106201, -0.030883789, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, -0.018737793, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.70544434, 0.09106445, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(18.0, -0.55859375, 0.005065918, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(24.0, 0.0, -0.030639648, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(24.0, -0.8342285, -0.033081055, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(30.0, -0.8041992, 0.020202637, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(50.0, 0.0, 0.003540039, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(50.0, -1.0830078, 0.015686035, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(61.0, -1.0407715, -0.03173828, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(69.0, -1.3012695, -0.0040893555, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, -1.5510254, -0.009399414, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(92.0, 0.0, 0.07281494, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(92.0, -1.1004639, 0.07611084, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, -0.08355713, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, -1.4197998, -0.03643799, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(103.0, -1.5430908, 0.02758789, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.050842285, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.6861572, 0.20019531, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 27,
+            parent: 26,
+            name: "JOBJ_27",
+            joint: ::melee_anim_rs::bone::Joint {tx: 2.2035387, ty: 0.022107996, tz: -0.0045799995, rx: -0.001217, ry: 0.000701, rz: 0.01118},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.16088867, -0.18908691, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(5.0, 0.0, -0.1204834, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(5.0, -1.2955322, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(15.0, -1.9683838, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(31.0, -1.9683838, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(40.0, -1.6845703, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(48.0, -1.6845703, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(56.0, -1.6547852, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(66.0, -1.6798096, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(74.0, -1.6462402, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, -1.6049805, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.16088867, 0.06011963, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.36132813, 0.0005563066, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(5.0, 0.0, -0.017437948, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(5.0, 0.36468506, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.09979248, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(31.0, 0.09979248, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(40.0, -0.09173584, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(48.0, -0.09173584, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(56.0, 0.104003906, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(66.0, -0.09729004, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(74.0, -0.013183594, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.15246582, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.36132813, 0.008703257, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.34918213, 0.057922363, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(5.0, 0.0, 0.08068848, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(5.0, -0.0015258789, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.86151123, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(31.0, 0.86151123, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(40.0, -0.65008545, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(48.0, -0.65008545, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(56.0, 0.9074707, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(66.0, -0.7000122, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(74.0, -0.97772217, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.3199463, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.34918213, -0.001159668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 2.2473755, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 2.2473755, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 28,
+            parent: 27,
+            name: "JOBJ_28",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.4885769, ty: -0.4343559, tz: 0.20518398, rx: 0.0028470003, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.0649414, -0.08190918, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, -0.22949219, -0.035705566, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(31.0, -0.19140625, 0.040283203, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(38.0, 0.0, 0.061645508, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(38.0, 0.65075684, 0.14147949, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(43.0, 0.0, 0.12854004, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(43.0, 1.0935059, 0.05316162, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(44.0, 1.1488037, 0.055358887, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(51.0, 0.0, -0.015930176, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(51.0, 0.55529785, -0.1373291, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, -0.42297363, -0.081848145, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(64.0, 0.0, -0.06994629, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(64.0, -0.5830078, 0.013000488, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(66.0, 0.0, 0.06781006, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(66.0, -0.390625, 0.12451172, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(69.0, 0.0, 0.14483643, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(69.0, -0.041015625, 0.076049805, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(109.0, 0.0, 0.037231445, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(109.0, 1.1207275, 0.027832031, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.055725098, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.0649414, -0.055725098, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.13317871, -0.020385742, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(19.0, -0.21228027, -0.0057373047, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, -0.16149902, 0.018676758, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.0, 0.02923584, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.12780762, -0.014709473, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(43.0, 0.10876465, -0.018981934, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(44.0, 0.0, -0.028808594, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(44.0, 0.0, -0.028808594, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(44.0, 0.079956055, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.13317871, 0.00079345703, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 29,
+            parent: 28,
+            name: "JOBJ_29",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.53306496, ty: -0.00086699985, tz: -0.08026499, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.2037964, -0.06121826, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, 0.23602295, -0.026794434, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.31292725, 0.04827881, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(41.0, 0.0, 0.07409668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(41.0, 1.1487427, 0.051696777, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(46.0, 0.0, 0.027770996, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(46.0, 1.1322632, -0.044799805, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(55.0, 0.0, -0.06744385, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(55.0, 0.42626953, -0.03375244, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(65.0, 0.0, -0.008239746, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(65.0, 0.8890991, 0.081726074, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(70.0, 0.0, 0.10974121, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(70.0, 1.3493652, 0.037353516, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(109.0, 0.0, 0.007751465, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(109.0, 1.1975098, -0.004638672, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.006286621, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.2037964, 0.006286621, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 30,
+            parent: 27,
+            name: "JOBJ_30",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.4900949, ty: -0.077678986, tz: 0.21593697, rx: 0.000252, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.033295155, -0.0051077977, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(38.0, 0.0184459, 0.009142682, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(53.0, 0.018335903, -8.851266e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.033295155, 0.00033013002, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.1621094, -0.06738281, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, 0.09631348, -0.02947998, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.18011475, 0.05230713, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(41.0, 0.0, 0.079956055, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(41.0, 1.0962524, 0.06213379, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(52.0, 0.0, 0.039978027, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(52.0, 1.1948853, 0.0006713867, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.1621094, -0.00036621094, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 31,
+            parent: 30,
+            name: "JOBJ_31",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.5696939, ty: 0.010306997, tz: -0.058733992, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.2037964, -0.06121826, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, 0.23602295, -0.026794434, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.31292725, 0.04827881, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(41.0, 0.0, 0.07409668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(41.0, 1.1487427, 0.051696777, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(51.0, 0.0, 0.027770996, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(51.0, 1.2022095, 0.00061035156, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.2037964, 0.00030517578, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 32,
+            parent: 27,
+            name: "JOBJ_32",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.4524039, ty: 0.26060894, tz: 0.19874696, rx: 0.00044800004, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.049926758, 0.0076616984, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.060546875, -0.008991904, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(43.0, -0.040771484, 0.007051162, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(68.0, -0.029418945, -0.00029019924, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.049926758, -0.0004952023, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.2593384, -0.05291748, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, 0.42230225, -0.023254395, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.49005127, 0.04296875, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(41.0, 0.0, 0.06628418, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(41.0, 1.218689, 0.037841797, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(49.0, 0.0, 0.011413574, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(49.0, 1.2130127, 0.00024414063, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.2593384, 0.0012207031, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 33,
+            parent: 32,
+            name: "JOBJ_33",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.58174896, ty: 0.0048869993, tz: -0.07242199, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.2037964, -0.06121826, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, 0.23602295, -0.026794434, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.31292725, 0.04827881, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(41.0, 0.0, 0.07409668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(41.0, 1.1487427, 0.051696777, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(51.0, 0.0, 0.027770996, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(51.0, 1.2022095, 0.00061035156, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.2037964, 0.00030517578, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 34,
+            parent: 27,
+            name: "JOBJ_34",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.3429968, ty: 0.6126429, tz: 0.15327299, rx: 0.0028470003, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.16644287, 0.025512695, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(18.0, 0.25738525, 0.008666992, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.20184326, -0.02331543, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.0, -0.03656006, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(42.0, -0.159729, 0.013061523, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(58.0, 0.0, 0.023742676, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(58.0, -0.091308594, -0.00024414063, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.16644287, -0.0016479492, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.3426514, -0.040527344, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(17.0, 0.6848755, -0.015686035, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.75567627, 0.03491211, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(41.0, 0.0, 0.054504395, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(41.0, 1.3236084, 0.017028809, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(47.0, 0.0, -0.013000488, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(47.0, 1.2352905, -0.0017089844, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.3426514, 0.0025634766, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 35,
+            parent: 34,
+            name: "JOBJ_35",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.59069395, ty: 0.004715999, tz: -0.063636996, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.2037964, -0.06121826, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, 0.23602295, -0.026794434, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(32.0, 0.31292725, 0.04827881, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(41.0, 0.0, 0.07409668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(41.0, 1.1487427, 0.051696777, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(51.0, 0.0, 0.027770996, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(51.0, 1.2022095, 0.00061035156, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.2037964, 0.00030517578, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 36,
+            parent: 27,
+            name: "JOBJ_36",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.020701999, ty: 0.014937997, tz: 0.00013299998, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.026184082, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.41986084, 0.01159668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(31.0, 0.36016846, -0.02746582, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(40.0, -0.2680664, -0.046875, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(45.0, -0.29644775, 0.016601563, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(51.0, -0.08496094, 0.025756836, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(56.0, -0.012939453, -0.01171875, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(66.0, -0.2612915, 0.00018310547, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.00579834, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.8102417, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(31.0, 0.83306885, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(40.0, -0.8109131, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(45.0, -0.76464844, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(51.0, -0.07849121, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(56.0, 0.8618164, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(66.0, -0.7821655, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.2199707, 0.010131836, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.38226318, 0.0025634766, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(31.0, 0.3007202, -0.009216309, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(40.0, 0.15179443, -0.010070801, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(45.0, 0.15911865, -0.018432617, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(51.0, -0.051086426, -0.035095215, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(56.0, -0.22711182, 0.018249512, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(66.0, 0.22290039, 0.008239746, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.2199707, -6.1035156e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.016601563, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.016601563, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 37,
+            parent: 27,
+            name: "JOBJ_37",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.900136, ty: -0.8265218, tz: -0.30175498, rx: 0.0, ry: 0.0, rz: -0.24},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.088378906, -0.009671807, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.066345215, -0.0049919016, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(31.0, -0.066345215, -0.003287473, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(40.0, -0.14849854, 0.004424228, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(52.0, 0.026550293, 0.0032714917, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(68.0, -0.056884766, -0.00045788853, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0013240919, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.42755127, 0.021448035, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.77072144, 0.011069953, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(31.0, 0.77072144, -0.01362517, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(40.0, 0.43008423, -0.018388301, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(52.0, 0.384552, -0.0010345334, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(68.0, 0.40112305, -0.0006121126, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.34906006, -0.0012107076, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.16729736, -0.01928711, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.47644043, -0.0099487305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(31.0, -0.47644043, -0.0029296875, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(40.0, -0.5499878, 0.024353027, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(52.0, 0.03564453, 0.0033569336, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(68.0, -0.45507813, -0.00061035156, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.010559082, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 38,
+            parent: 37,
+            name: "JOBJ_38",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.43053398, ty: -0.03955199, tz: 5.9999998e-6, rx: 0.0015750001, ry: -0.0, rz: -0.091613},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.0017877775, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(52.0, -0.09472656, -0.0013934148, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(68.0, -0.09472656, 0.0016336587, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0022035264, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.0051503056, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(52.0, -0.27294922, -0.0040142084, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(68.0, -0.27294922, 0.0047063134, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.006348013, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.01159668, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.18603516, -0.0059814453, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(31.0, -0.18603516, 0.0025024414, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(40.0, -0.122924805, -0.03186035, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(52.0, -0.8555908, -0.026123047, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(68.0, -0.8555908, 0.014709473, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.019897461, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 39,
+            parent: 25,
+            name: "JOBJ_39",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.062805, ty: 0.14112198, tz: 0.010377999, rx: 1.5709311, ry: 0.0038970003, rz: -0.006474},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 40,
+            parent: 22,
+            name: "JOBJ_40",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 1.66707, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.10241699, 0.0065307617, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0026245117, 0.0051879883, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(63.0, 0.22747803, -0.0065307617, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(73.0, -0.3772583, -0.041748047, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(79.0, -0.44073486, 0.020690918, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(93.0, 0.036499023, 0.018432617, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(103.0, 0.0026245117, -0.008117676, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.10241699, -0.013122559, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.23937988, -0.0056152344, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.14941406, -0.0012207031, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(63.0, 0.15997314, 0.0013427734, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(73.0, 0.22937012, 0.053100586, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(79.0, 1.010315, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(93.0, 0.69647217, -0.035827637, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(103.0, 0.14941406, -0.026855469, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.23937988, 0.011230469, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.053833008, 0.021362305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.28808594, 0.0061035156, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(63.0, 0.33306885, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(73.0, 0.28833008, -0.036987305, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(79.0, -0.2592163, -0.013793945, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(93.0, 0.012268066, 0.022766113, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(103.0, 0.28808594, -0.0038452148, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.053833008, -0.04272461, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.6670532, 0.014520451, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.002255604, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 1.899353, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(103.0, 1.899353, -0.0024455495, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.6670532, -0.02903997, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 41,
+            parent: 40,
+            name: "JOBJ_41",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.0, ty: 0.525, tz: -0.15, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 42,
+            parent: 41,
+            name: "JOBJ_42",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.7, ty: 2.5, tz: 2.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 43,
+            parent: 42,
+            name: "JOBJ_43",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: 0.0, ry: 0.47123906, rz: 0.296706},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 44,
+            parent: 42,
+            name: "JOBJ_44",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: 0.0, ry: 0.47123906, rz: 0.296706},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 45,
+            parent: 42,
+            name: "JOBJ_45",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: -0.17453295, ry: 0.4712389, rz: 0.29670593},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 46,
+            parent: 41,
+            name: "JOBJ_46",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.7, ty: 2.5, tz: 2.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 47,
+            parent: 46,
+            name: "JOBJ_47",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: 3.1415927, ry: 0.47123876, rz: 2.8448868},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 48,
+            parent: 46,
+            name: "JOBJ_48",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: 3.1415927, ry: 0.47123876, rz: 2.8448868},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 49,
+            parent: 46,
+            name: "JOBJ_49",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: -2.9670596, ry: 0.4712388, rz: 2.8448868},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 50,
+            parent: 41,
+            name: "JOBJ_50",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.274217, ty: 3.501988, tz: 0.532216, rx: -0.39350587, ry: 0.10447997, rz: 0.5818228},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.39349365, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.39349365, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.10446167, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.10446167, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.5818176, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.5818176, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 51,
+            parent: 41,
+            name: "JOBJ_51",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 1.238708, tz: 1.202795, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(17.0, 0.002380371, 0.003479004, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(34.0, 0.269989, 0.002380371, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(38.0, 0.0, -0.042175293, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(38.0, 0.0, -0.042114258, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.0, 0.042175293, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(42.0, 0.269989, 0.042114258, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(64.0, 0.0, -0.0010986328, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(64.0, 0.109558105, 0.006713867, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(72.0, 0.0, 0.018005371, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(72.0, 0.29910278, 0.007873535, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(79.0, 0.46325684, 0.009033203, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(80.0, 0.0, -0.072387695, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(80.0, 0.39086914, -0.072387695, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(82.0, 0.0, -0.15924072, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(82.0, 0.072387695, -0.15924072, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(83.0, 0.0, -0.072387695, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(83.0, 0.0, -0.072387695, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.00048828125, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.00018310547, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 52,
+            parent: 41,
+            name: "JOBJ_52",
+            joint: ::melee_anim_rs::bone::Joint {tx: -1.310217, ty: 3.5019884, tz: 0.53221613, rx: 2.7480867, ry: -0.10447842, rz: -0.58182365},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.39349365, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.39349365, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 3.2459717, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 3.2459717, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 2.5597534, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 2.5597534, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 53,
+            parent: 22,
+            name: "JOBJ_53",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.8, ty: 0.96521, tz: 0.217753, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, -0.018749999, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.2999878, -0.005, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.2999878, 0.0031578944, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.005882322, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.7999878, 0.011789922, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.003143979, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.6113281, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.6113281, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.6113281, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.7999878, -0.007859864, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.96520996, 0.0068610907, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.0018296242, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 1.0749512, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, 1.0749512, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, 1.0749512, -0.0021955487, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.96520996, -0.0045740115, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.21774292, 0.014426781, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.0038471415, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.44857788, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.44857788, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.44857788, -0.0046165693, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.21774292, -0.0096177505, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 54,
+            parent: 53,
+            name: "JOBJ_54",
+            joint: ::melee_anim_rs::bone::Joint {tx: -1.787114, ty: -0.010107999, tz: 0.010752999, rx: -1.570796, ry: -1.4210855e-14, rz: 3.1415923},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.875, 0.015678808, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.0041810153, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -1.6241455, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, -1.6241455, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, -1.6241455, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.875, -0.010452428, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.11968994, 0.0036188136, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.00096501695, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.061828613, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.061828613, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, -0.061828613, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.11968994, -0.0024125166, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.41296387, 0.0124718435, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.0033258249, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.6125183, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.6125183, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.6125183, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.41296387, -0.008314474, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 55,
+            parent: 54,
+            name: "JOBJ_55",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: -0.0024180003, ry: 0.0046650004, rz: 0.12852202},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.4923706, 0.01763916, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(7.0, 0.60476685, 0.040893555, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, 0.9345093, 0.0049438477, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, 0.97662354, 0.0016479492, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(79.0, 0.86608887, -0.011352539, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(87.0, 0.6196594, -0.032104492, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(103.0, 0.0, -0.021972656, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(103.0, 0.53338623, -0.0040283203, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.4923706, -0.0072021484, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.72320557, 0.003105379, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(10.0, -0.7662964, 0.009325558, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(26.0, -0.6721802, 0.0020173446, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(51.0, -0.6697998, 0.0028987841, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(64.0, -0.6055298, 0.004179199, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(80.0, -0.59155273, -0.01195686, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(89.0, 0.0, -0.021451764, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(89.0, -0.8375244, -0.0035588488, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(104.0, -0.739624, 0.0052397694, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.72320557, 0.00065780076, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.47399902, -0.0211792, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(7.0, -0.7150879, -0.061279297, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -1.1099854, -0.016235352, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(51.0, -1.1674805, 0.0056152344, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(83.0, -0.8585205, 0.018920898, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, -0.6270752, 0.013000488, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.47399902, 0.008850098, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 56,
+            parent: 55,
+            name: "JOBJ_56",
+            joint: ::melee_anim_rs::bone::Joint {tx: 2.0125587, ty: -0.0, tz: -1.9999998e-5, rx: 0.0, ry: 0.0, rz: -0.15806502},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.3631592, -0.06427002, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(9.0, -1.7995605, -0.039794922, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(27.0, -2.0423584, -0.004272461, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(57.0, -2.026245, 0.0032348633, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(73.0, -1.944458, 0.007446289, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(84.0, -1.6418457, 0.06323242, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(90.0, -1.4260254, 0.0068969727, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(105.0, -1.4093018, 0.006164551, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.3631592, 0.011413574, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 57,
+            parent: 56,
+            name: "JOBJ_57",
+            joint: ::melee_anim_rs::bone::Joint {tx: 2.2053487, ty: 0.019564997, tz: 0.0038729992, rx: 0.000966, ry: -0.000452, rz: 0.025074998},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.565979, 0.061645508, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.015319824, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, 1.5526123, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, 1.4882813, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.565979, -0.018066406, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.2293683, -0.00014752455, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.0, 0.000196928, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.23172869, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.21755262, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_LIN),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.2293683, -0.0002316788, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.022399902, -0.048583984, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.80041504, -0.008178711, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.51348877, 0.008178711, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.022399902, 0.0095825195, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 2.2694702, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 2.2694702, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 58,
+            parent: 57,
+            name: "JOBJ_58",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.4916339, ty: -0.42293298, tz: -0.20683497, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.0338135, 0.05895996, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.14221191, 0.01763916, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(56.0, -0.084472656, -0.012878418, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(62.0, -0.23596191, -0.06121826, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(71.0, 0.0, -0.0836792, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(71.0, -1.0338135, -0.034118652, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.016906738, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.0338135, 0.0004272461, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.019195557, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.019195557, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 59,
+            parent: 58,
+            name: "JOBJ_59",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.53305995, ty: 0.0029059998, tz: 0.080200985, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.1263428, 0.022299515, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(17.0, -0.77856445, 0.0051507866, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, -0.78344727, -0.005733053, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(70.0, -1.1134033, -0.023681028, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, -0.012917995, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, -1.1480713, 0.0022291325, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.1263428, 0.00016831179, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 60,
+            parent: 57,
+            name: "JOBJ_60",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.4905909, ty: -0.066217996, tz: -0.21633196, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.033935547, -0.0009870633, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.033935547, -7.4506092e-6, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.0986328, 0.03326416, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(16.0, -0.58703613, 0.0079956055, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.59521484, -0.008728027, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(71.0, 0.0, -0.018310547, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(71.0, -1.0986328, -0.01928711, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, -0.009521484, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.0986328, 0.00024414063, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_NODE,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 61,
+            parent: 60,
+            name: "JOBJ_61",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.56960493, ty: 0.014204999, tz: 0.05871999, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.1263428, 0.022299515, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(17.0, -0.77856445, 0.0051507866, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, -0.78344727, -0.005733053, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(70.0, -1.1134033, -0.023681028, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, -0.012917995, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, -1.1480713, 0.0022291325, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.1263428, 0.00016831179, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_NODE,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 62,
+            parent: 57,
+            name: "JOBJ_62",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.4504739, ty: 0.27172798, tz: -0.19794796, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.050975118, 0.0014805928, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(75.0, 0.049533274, -0.00027833987, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.050975118, 1.1176954e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.1634521, 0.007621967, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(22.0, -1.0367432, 0.0013551494, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -1.0482178, -0.003101018, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(74.0, -1.1693115, -0.001637378, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.1634521, 5.752428e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_NODE,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 63,
+            parent: 62,
+            name: "JOBJ_63",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.5817049, ty: 0.008840999, tz: 0.072406985, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.1263428, 0.022299515, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(17.0, -0.77856445, 0.0051507866, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, -0.78344727, -0.005733053, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(70.0, -1.1134033, -0.023681028, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, -0.012917995, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, -1.1480713, 0.0022291325, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.1263428, 0.00016831179, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_NODE,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 64,
+            parent: 57,
+            name: "JOBJ_64",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.3385499, ty: 0.62280494, tz: -0.15122397, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.16989136, 0.0049353032, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(34.0, 0.25854492, 0.00022081767, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(62.0, 0.23666382, -0.0060649165, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(79.0, 0.16235352, -0.00045702342, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.16989136, 3.7250968e-5, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.2189941, -0.014394519, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(18.0, -1.4466553, -0.0031732896, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(60.0, -1.4365234, 0.005856358, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(72.0, -1.2148438, 0.0039166445, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.2189941, -0.00010865697, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_NODE,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 65,
+            parent: 64,
+            name: "JOBJ_65",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.5906509, ty: 0.008913999, tz: 0.06359099, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.1263428, 0.022299515, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(17.0, -0.77856445, 0.0051507866, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(59.0, -0.78344727, -0.005733053, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(70.0, -1.1134033, -0.023681028, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(96.0, 0.0, -0.012917995, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SLP),
+                        ::melee_anim_rs::animation::Key::new(96.0, -1.1480713, 0.0022291325, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.1263428, 0.00016831179, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_NODE,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 66,
+            parent: 57,
+            name: "JOBJ_66",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0039079995, ty: 0.0029499999, tz: -2.3999997e-5, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.093444824, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.093444824, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(15.0, 0.8258667, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(60.0, 0.8258667, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.20996094, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(15.0, -0.34692383, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(60.0, -0.34692383, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.20996094, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_SPL0),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_NODE,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 67,
+            parent: 57,
+            name: "JOBJ_67",
+            joint: ::melee_anim_rs::bone::Joint {tx: 1.1807717, ty: -0.032603998, tz: 0.19945697, rx: 0.0, ry: -1.570451, rz: -3.1415873},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -1.5706787, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, -1.5706787, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 3.1416016, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 3.1416016, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.1799927, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.1799927, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.13998413, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.13998413, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_TRAZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.19998169, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.19998169, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_NODE,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 1.0, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 68,
+            parent: 57,
+            name: "JOBJ_68",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.9060799, ty: -0.82109696, tz: 0.29876694, rx: 0.0, ry: 0.0, rz: -0.331613},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.15008545, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.15008545, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.5440674, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.5440674, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, -0.0758667, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, -0.0758667, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 69,
+            parent: 68,
+            name: "JOBJ_69",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.43229893, ty: 0.011495999, tz: 0.05172099, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTX,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.4512329, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.4512329, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTY,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.07199097, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.07199097, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+                ::melee_anim_rs::embed::StaticTrack {
+                    r#type: ::melee_anim_rs::animation::TrackType::HSD_A_J_ROTZ,
+                    keys: &[
+                        ::melee_anim_rs::animation::Key::new(0.0, 0.24484253, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                        ::melee_anim_rs::animation::Key::new(110.0, 0.24484253, 0.0, ::melee_anim_rs::animation::InterpolationType::HSD_A_OP_CON),
+                    ],
+                },
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 70,
+            parent: 55,
+            name: "JOBJ_70",
+            joint: ::melee_anim_rs::bone::Joint {tx: -0.03685899, ty: 0.14098297, tz: -0.010057999, rx: -1.5723271, ry: 0.003825001, rz: 3.134789},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 71,
+            parent: 3,
+            name: "JOBJ_71",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: -8.300001, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+            ],
+        },
+        ::melee_anim_rs::embed::StaticBone {
+            index: 72,
+            parent: 0,
+            name: "JOBJ_72",
+            joint: ::melee_anim_rs::bone::Joint {tx: 0.0, ty: 0.0, tz: 0.0, rx: 0.0, ry: -0.0, rz: 0.0},
+            tracks: &[
+            ],
+        },
+    ],
+    hurtboxes: &[
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 4, x1: -1.0, y1: 0.0, z1: 0.0, x2: 1.0, y2: 0.0, z2: 0.0, size: 2.04, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Mid, grabable: true},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 22, x1: 1.0, y1: 0.5, z1: 0.0, x2: -1.0, y2: 1.0, z2: 0.0, size: 2.4, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Mid, grabable: true},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 41, x1: 0.0, y1: 2.0, z1: 0.0, x2: 0.0, y2: 3.0, z2: 0.3, size: 2.5, r#type: ::melee_anim_rs::hurtbox::HurtboxType::High, grabable: true},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 41, x1: 0.0, y1: 1.6, z1: 2.0, x2: 0.0, y2: 1.6, z2: 2.5, size: 1.5, r#type: ::melee_anim_rs::hurtbox::HurtboxType::High, grabable: true},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 55, x1: 0.0, y1: 0.2, z1: 0.0, x2: 2.0, y2: 0.2, z2: 0.0, size: 1.2, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Mid, grabable: true},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 25, x1: 0.0, y1: 0.2, z1: 0.0, x2: 2.0, y2: 0.2, z2: 0.0, size: 1.2, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Mid, grabable: true},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 56, x1: 0.0, y1: 0.0, z1: 0.0, x2: 2.35, y2: 0.0, z2: 0.0, size: 0.96, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Mid, grabable: false},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 26, x1: 0.0, y1: 0.0, z1: 0.0, x2: 2.35, y2: 0.0, z2: 0.0, size: 0.96, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Mid, grabable: false},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 12, x1: 0.0, y1: 0.0, z1: 0.0, x2: 3.2, y2: 0.0, z2: 0.0, size: 1.44, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Low, grabable: true},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 6, x1: 0.0, y1: 0.0, z1: 0.0, x2: 3.2, y2: 0.0, z2: 0.0, size: 1.44, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Low, grabable: true},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 13, x1: 0.0, y1: 0.0, z1: 0.0, x2: 3.1, y2: 0.0, z2: 0.0, size: 1.2, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Low, grabable: false},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 7, x1: 0.0, y1: 0.0, z1: 0.0, x2: 3.1, y2: 0.0, z2: 0.0, size: 1.2, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Low, grabable: false},
+        ::melee_anim_rs::hurtbox::Hurtbox {bone_index: 18, x1: 0.0, y1: 0.0, z1: 0.8, x2: 0.0, y2: 0.0, z2: -0.8, size: 1.62, r#type: ::melee_anim_rs::hurtbox::HurtboxType::Mid, grabable: true},
+    ],
+};
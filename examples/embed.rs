@@ -0,0 +1,19 @@
+// Writes assets/animation.rs, the example assets as a `StaticAnimation`, which render.rs embeds.
+// A build script can't call the crate it builds, so the generated file is checked in and
+// tests/embed.rs fails when it no longer matches the assets.
+
+use std::fs::File;
+use std::io::BufWriter;
+
+use melee_anim_rs::Source;
+
+fn main() -> std::io::Result<()> {
+    let assets = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/assets/");
+    let anim = melee_anim_rs::get_animation_with_hurtboxes(
+        Source::Path(&format!("{}model.smd", assets)),
+        Source::Path(&format!("{}hurtboxes.csv", assets)),
+        Source::Path(&format!("{}animation.figatree", assets)),
+    ).unwrap();
+    let mut out = BufWriter::new(File::create(format!("{}animation.rs", assets))?);
+    melee_anim_rs::embed::write_static_animation(&mut out, "ANIMATION", &anim)
+}
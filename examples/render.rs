@@ -2,7 +2,8 @@ use ggez::{graphics, Context, ContextBuilder, GameResult};
 use ggez::mint::Point2;
 use ggez::event::{self, EventHandler};
 
-use melee_anim_rs::animation::Animation;
+use melee_anim_rs::bone::Model;
+use melee_anim_rs::hurtbox::WorldHurtbox;
 
 
 #[cfg(host_family = "windows")]
@@ -15,6 +16,9 @@ macro_rules! PATH_SEPARATOR {() => (
 )}
 
 
+// Generated from the assets by `cargo run --example embed`.
+include!(concat!("assets", PATH_SEPARATOR!(), "animation.rs"));
+
 const SCREEN_SIZE: (f32, f32) = (640.0, 528.0);
 const RATIO: f32 = 10.0;
 
@@ -33,18 +37,19 @@ pub fn scale_one(p: (f32, f32)) -> Point2<f32> {
     return Point2 { x: RATIO * p.0 + w / 2.0, y: -RATIO * p.1 + h / 2.0 };
 }
 
-pub fn draw_hurtboxes(hurtboxes_2d: Vec<(melee_anim_rs::animation::Point2<f32>, melee_anim_rs::animation::Point2<f32>, f32)>, ctx: &mut Context) -> GameResult<()> {
+pub fn draw_hurtboxes(hurtboxes: &[WorldHurtbox], ctx: &mut Context) -> GameResult<()> {
 
-    for (c1, c2, size) in &hurtboxes_2d {
+    for hurtbox in hurtboxes {
+        let (c1, c2) = (hurtbox.p1, hurtbox.p2);
         let line_options = graphics::StrokeOptions::default()
-        .with_line_width(2. * size * RATIO)
+        .with_line_width(2. * hurtbox.size * RATIO)
         .with_start_cap(graphics::LineCap::Round)
         .with_end_cap(graphics::LineCap::Round);
 
         let hbc = graphics::Mesh::new_polyline(
         ctx,
         graphics::DrawMode::Stroke(line_options),
-        &[scale_one((c2.x, c2.y)), scale_one((c1.x, c1.y))],
+        &[scale_one((c2.z, c2.y)), scale_one((c1.z, c1.y))],
         [1., 1., 0., 0.2].into(),
         )?;
         graphics::draw(ctx, &hbc, graphics::DrawParam::default())?;
@@ -61,19 +66,16 @@ fn main() -> GameResult {
 }
 
 struct MyGame {
-    anim: Animation,
+    scratch: Model,
+    hurtboxes: Vec<WorldHurtbox>,
     frame: i32,
 }
 
 impl MyGame {
     pub fn new(_ctx: &mut Context) -> MyGame {
-        let smd_data: &[u8] = include_bytes!(concat!("assets", PATH_SEPARATOR!(), "model.smd"));
-        let hurtbox_data: &[u8] = include_bytes!(concat!("assets", PATH_SEPARATOR!(), "hurtboxes.csv"));
-        let figatree_data: &[u8] = include_bytes!(concat!("assets", PATH_SEPARATOR!(), "animation.figatree"));
-
-        let anim = melee_anim_rs::get_animation_with_hurtboxes(smd_data, hurtbox_data, figatree_data).unwrap();
         MyGame {
-            anim,
+            scratch: ANIMATION.model(),
+            hurtboxes: Vec::new(),
             frame: 0,
         }
     }
@@ -87,13 +89,13 @@ impl EventHandler for MyGame {
 
     fn draw(&mut self, ctx: &mut Context) -> GameResult<()> {
         let slow = 1.;
-        if self.frame >= (slow * self.anim.frame_count) as i32 {
-            self.frame -= (slow * self.anim.frame_count) as i32;
+        if self.frame >= (slow * ANIMATION.frame_count) as i32 {
+            self.frame -= (slow * ANIMATION.frame_count) as i32;
         }
         self.frame += 1;
         graphics::clear(ctx, graphics::BLACK);
-        let hurtboxes = self.anim.get_frame_hurtboxes_2d((self.frame as f32) / slow);
-        draw_hurtboxes(hurtboxes, ctx)?;
+        ANIMATION.query_hurtboxes_into((self.frame as f32) / slow, &mut self.scratch, &mut self.hurtboxes);
+        draw_hurtboxes(&self.hurtboxes, ctx)?;
         graphics::present(ctx)
     }
 }
@@ -1,11 +1,16 @@
+use std::fmt;
 use std::str::FromStr;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Cursor};
+use std::io::{BufRead, BufReader, Cursor, Write};
 
-use crate::bone::{Model, ParseSMDError};
-use crate::hurtbox::Hurtbox;
+use crate::bone::Model;
+use crate::error::{parse_f32, Cause, Error, Input, ParseMode};
+use crate::figatree::{self, Diagnostic, Statement};
+use crate::hurtbox::{Hurtbox, WorldHurtbox};
+use crate::Source;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InterpolationType {
     HSD_A_OP_NONE,
     HSD_A_OP_CON,
@@ -17,6 +22,7 @@ pub enum InterpolationType {
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TrackType {
     HSD_A_J_NONE,
     HSD_A_J_ROTX,
@@ -38,6 +44,7 @@ pub struct Point2<T> {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Key {
     frame: f32,
     value: f32,
@@ -57,68 +64,104 @@ pub struct AnimState {
     op: InterpolationType,
 }
 
+// Remembers the state of a track between calls to `Track::get_value_with_cursor`.
 #[derive(Debug, PartialEq, Clone)]
+pub struct TrackCursor {
+    state: Option<AnimState>,
+    start: f32,
+    end: f32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Track {
     pub r#type: TrackType,
     pub keys: Vec<Key>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Animation {
     pub frame_count: f32,
     pub model: Model,
     pub hurtboxes: Vec<Hurtbox>,
 }
 
-#[derive(Debug, Clone)]
-pub struct ParseKeyError;
-
-impl From<std::num::ParseIntError> for ParseKeyError {
-    fn from(_: std::num::ParseIntError) -> Self {
-        ParseKeyError{}
+impl fmt::Display for InterpolationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            InterpolationType::HSD_A_OP_NONE => "HSD_A_OP_NONE",
+            InterpolationType::HSD_A_OP_CON => "HSD_A_OP_CON",
+            InterpolationType::HSD_A_OP_LIN => "HSD_A_OP_LIN",
+            InterpolationType::HSD_A_OP_SPL0 => "HSD_A_OP_SPL0",
+            InterpolationType::HSD_A_OP_SPL => "HSD_A_OP_SPL",
+            InterpolationType::HSD_A_OP_SLP => "HSD_A_OP_SLP",
+            InterpolationType::HSD_A_OP_KEY => "HSD_A_OP_KEY",
+        };
+        f.write_str(name)
     }
 }
 
-impl From<std::num::ParseFloatError> for ParseKeyError {
-    fn from(_: std::num::ParseFloatError) -> Self {
-        ParseKeyError{}
+impl fmt::Display for TrackType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            TrackType::HSD_A_J_NONE => "HSD_A_J_NONE",
+            TrackType::HSD_A_J_ROTX => "HSD_A_J_ROTX",
+            TrackType::HSD_A_J_ROTY => "HSD_A_J_ROTY",
+            TrackType::HSD_A_J_ROTZ => "HSD_A_J_ROTZ",
+            TrackType::HSD_A_J_PATH => "HSD_A_J_PATH",
+            TrackType::HSD_A_J_TRAX => "HSD_A_J_TRAX",
+            TrackType::HSD_A_J_TRAY => "HSD_A_J_TRAY",
+            TrackType::HSD_A_J_TRAZ => "HSD_A_J_TRAZ",
+            TrackType::HSD_A_J_SCAX => "HSD_A_J_SCAX",
+            TrackType::HSD_A_J_SCAY => "HSD_A_J_SCAY",
+            TrackType::HSD_A_J_SCAZ => "HSD_A_J_SCAZ",
+            TrackType::HSD_A_J_NODE => "HSD_A_J_NODE",
+        };
+        f.write_str(name)
     }
 }
 
-impl From<std::io::Error> for ParseKeyError {
-    fn from(_: std::io::Error) -> Self {
-        ParseKeyError{}
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.frame, self.value, self.tan, self.interpolation_type)
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct ParseFigaTreeError;
+impl Key {
+    pub const fn new(frame: f32, value: f32, tan: f32, interpolation_type: InterpolationType) -> Key {
+        Key {frame, value, tan, interpolation_type}
+    }
 
-impl From<std::num::ParseIntError> for ParseFigaTreeError {
-    fn from(_: std::num::ParseIntError) -> Self {
-        ParseFigaTreeError{}
+    pub fn frame(&self) -> f32 {
+        self.frame
     }
-}
 
-impl From<std::num::ParseFloatError> for ParseFigaTreeError {
-    fn from(_: std::num::ParseFloatError) -> Self {
-        ParseFigaTreeError{}
+    pub fn value(&self) -> f32 {
+        self.value
     }
-}
 
-impl From<std::io::Error> for ParseFigaTreeError {
-    fn from(_: std::io::Error) -> Self {
-        ParseFigaTreeError{}
+    pub fn tan(&self) -> f32 {
+        self.tan
     }
-}
 
-impl From<ParseKeyError> for ParseFigaTreeError {
-    fn from(_: ParseKeyError) -> Self {
-        ParseFigaTreeError{}
+    pub fn interpolation_type(&self) -> InterpolationType {
+        self.interpolation_type
     }
-}
 
-impl Key {
-    pub fn parse_interpolation_type(s: &str) -> Result<InterpolationType, ParseKeyError> {
+    // The frame is only changed through `Track::move_key`, which keeps keys ordered.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = value;
+    }
+
+    pub fn set_tan(&mut self, tan: f32) {
+        self.tan = tan;
+    }
+
+    pub fn set_interpolation_type(&mut self, interpolation_type: InterpolationType) {
+        self.interpolation_type = interpolation_type;
+    }
+
+    pub fn parse_interpolation_type(s: &str) -> Result<InterpolationType, Error> {
         match s {
             "HSD_A_OP_NONE" => Ok(InterpolationType::HSD_A_OP_NONE),
             "HSD_A_OP_CON" => Ok(InterpolationType::HSD_A_OP_CON),
@@ -127,27 +170,106 @@ impl Key {
             "HSD_A_OP_SPL" => Ok(InterpolationType::HSD_A_OP_SPL),
             "HSD_A_OP_SLP" => Ok(InterpolationType::HSD_A_OP_SLP),
             "HSD_A_OP_KEY" => Ok(InterpolationType::HSD_A_OP_KEY),
-            _ => Err(ParseKeyError),
+            _ => Err(Error::parse(Input::Animation, s, Cause::UnknownInterpolationType)),
         }
     }
 }
 
 impl FromStr for Key {
-    type Err = ParseKeyError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let key_data = s.split(" ").collect::<Vec<_>>();
+        let key_data = s.split_whitespace().collect::<Vec<_>>();
+        if key_data.len() != 4 {
+            return Err(Error::parse(Input::Animation, s, Cause::FieldCount {expected: 4, found: key_data.len()}));
+        }
         Ok(Key {
-            frame: f32::from_str(key_data[0])?,
-            value: f32::from_str(key_data[1])?,
-            tan: f32::from_str(key_data[2])?,
+            frame: parse_f32(Input::Animation, key_data[0])?,
+            value: parse_f32(Input::Animation, key_data[1])?,
+            tan: parse_f32(Input::Animation, key_data[2])?,
             interpolation_type: Self::parse_interpolation_type(key_data[3])?,
         })
     }
 }
 
+// Last frame with a key and the index of the value key there, which tracks hold past it.
+pub(crate) fn last_frame(keys: &[Key]) -> (f32, usize) {
+    let mut index = 0;
+    let mut m = 0.;
+    for (i,k) in keys.iter().enumerate() {
+        if k.frame > m || (k.frame == m && k.interpolation_type != InterpolationType::HSD_A_OP_SLP) {
+            index = i;
+            m = k.frame;
+        }
+    }
+    (m, index)
+}
+
+// State of the curve made by `keys` at `frame`, shared by tracks and embedded data.
+pub(crate) fn anim_state(keys: &[Key], frame: f32) -> AnimState {
+    let (last_frame, last_frame_index) = last_frame(keys);
+    if keys.len() > 1 && frame >= last_frame {
+        let key = &keys[last_frame_index];
+        return AnimState {
+            p0: key.value, p1: key.value,
+            d0: key.tan, d1: key.tan,
+            t0: key.frame, t1: key.frame,
+            op_intrp: key.interpolation_type, op: key.interpolation_type,
+        };
+    } else {
+        let (mut p0, mut p1, mut d0, mut d1, mut t0, mut t1) = (0., 0., 0., 0., 0., 0.);
+        let (mut op_intrp, mut op) = (InterpolationType::HSD_A_OP_CON, InterpolationType::HSD_A_OP_CON);
+        for key in keys {
+            op_intrp = op;
+            op = key.interpolation_type;
+            match op {
+                InterpolationType::HSD_A_OP_CON | InterpolationType::HSD_A_OP_LIN => {
+                    p0 = p1;
+                    p1 = key.value;
+                    if op_intrp != InterpolationType::HSD_A_OP_SLP {
+                        d0 = d1;
+                        d1 = 0.;
+                    }
+                    t0 = t1;
+                    t1 = key.frame;
+                },
+                InterpolationType::HSD_A_OP_SPL0 => {
+                    p0 = p1;
+                    p1 = key.value;
+                    d0 = d1;
+                    d1 = 0.;
+                    t0 = t1;
+                    t1 = key.frame;
+                },
+                InterpolationType::HSD_A_OP_SPL => {
+                    p0 = p1;
+                    p1 = key.value;
+                    d0 = d1;
+                    d1 = key.tan;
+                    t0 = t1;
+                    t1 = key.frame;
+                },
+                InterpolationType::HSD_A_OP_SLP => {
+                    d0 = d1;
+                    d1 = key.tan;
+                },
+                InterpolationType::HSD_A_OP_KEY => {
+                    p0 = key.value;
+                    p1 = key.value;
+                }
+                InterpolationType::HSD_A_OP_NONE => {},
+            }
+            if t1 > frame && key.interpolation_type != InterpolationType::HSD_A_OP_SLP {
+                break
+            }
+            op_intrp = key.interpolation_type;
+        }
+        return AnimState {p0, p1, d0, d1, t0, t1, op_intrp, op};
+    }
+}
+
 impl Track {
-    pub fn parse_type(s: &str) -> Result<TrackType, ParseFigaTreeError> {
+    pub fn parse_type(s: &str) -> Result<TrackType, Error> {
         match s {
             "HSD_A_J_NONE" => Ok(TrackType::HSD_A_J_NONE),
             "HSD_A_J_ROTX" => Ok(TrackType::HSD_A_J_ROTX),
@@ -161,99 +283,105 @@ impl Track {
             "HSD_A_J_SCAY" => Ok(TrackType::HSD_A_J_SCAY),
             "HSD_A_J_SCAZ" => Ok(TrackType::HSD_A_J_SCAZ),
             "HSD_A_J_NODE" => Ok(TrackType::HSD_A_J_NODE),
-            _ => Err(ParseFigaTreeError),
+            _ => Err(Error::parse(Input::Animation, s, Cause::UnknownTrackType)),
         }
     }
 
+    // Last frame with a key and the index of the value key there, which tracks hold past it.
     pub fn last_frame(&self) -> (f32, usize){
-        let mut index = 0;
-        let mut m = 0.;
-        for (i,k) in self.keys.iter().enumerate() {
-            if k.frame > m {
-                index = i;
-                m = k.frame;
-            }
-        }
-        (m, index)
+        last_frame(&self.keys)
     }
 
     pub fn get_anim_state(&self, frame: f32) -> AnimState {
-        let (last_frame, last_frame_index) = self.last_frame();
-        if self.keys.len() > 1 && frame >= last_frame {
-            let key = &self.keys[last_frame_index];
-            return AnimState {
-                p0: key.frame, p1: key.frame,
-                d0: key.value, d1: key.value,
-                t0: key.tan, t1: key.tan,
-                op_intrp: key.interpolation_type, op: key.interpolation_type,
-            };
-        } else {
-            let (mut p0, mut p1, mut d0, mut d1, mut t0, mut t1) = (0., 0., 0., 0., 0., 0.);
-            let (mut op_intrp, mut op) = (InterpolationType::HSD_A_OP_CON, InterpolationType::HSD_A_OP_CON);
-            for key in &self.keys {
-                op_intrp = op;
-                op = key.interpolation_type;
-                match op {
-                    InterpolationType::HSD_A_OP_CON | InterpolationType::HSD_A_OP_LIN => {
-                        p0 = p1;
-                        p1 = key.value;
-                        if op_intrp != InterpolationType::HSD_A_OP_SLP {
-                            d0 = d1;
-                            d1 = 0.;
-                        }
-                        t0 = t1;
-                        t1 = key.frame;
-                    },
-                    InterpolationType::HSD_A_OP_SPL0 => {
-                        p0 = p1;
-                        p1 = key.value;
-                        d0 = d1;
-                        d1 = 0.;
-                        t0 = t1;
-                        t1 = key.frame;
-                    },
-                    InterpolationType::HSD_A_OP_SPL => {
-                        p0 = p1;
-                        p1 = key.value;
-                        d0 = d1;
-                        d1 = key.tan;
-                        t0 = t1;
-                        t1 = key.frame;
-                    },
-                    InterpolationType::HSD_A_OP_SLP => {
-                        d0 = d1;
-                        d1 = key.tan;
-                    },
-                    InterpolationType::HSD_A_OP_KEY => {
-                        p0 = key.value;
-                        p1 = key.value;
+        anim_state(&self.keys, frame)
+    }
+
+    pub fn get_value(&self, frame: f32) -> f32 {
+        self.get_anim_state(frame).value(frame)
+    }
+
+    pub fn cursor(&self) -> TrackCursor {
+        TrackCursor {state: None, start: 0., end: 0.}
+    }
+
+    // Same result as `get_value`, but only recomputes the state when `frame` leaves the
+    // interval between two key frames that the cursor was last used in.
+    pub fn get_value_with_cursor(&self, frame: f32, cursor: &mut TrackCursor) -> f32 {
+        match &cursor.state {
+            Some(state) if cursor.start <= frame && frame < cursor.end => state.value(frame),
+            _ => {
+                let state = self.get_anim_state(frame);
+                let value = state.value(frame);
+                let (last_frame, _) = self.last_frame();
+                cursor.start = f32::NEG_INFINITY;
+                cursor.end = f32::INFINITY;
+                for breakpoint in self.keys.iter().map(|k| k.frame).chain([0., last_frame]) {
+                    if breakpoint <= frame {
+                        cursor.start = cursor.start.max(breakpoint);
+                    } else {
+                        cursor.end = cursor.end.min(breakpoint);
                     }
-                    InterpolationType::HSD_A_OP_NONE => {},
                 }
-                if t1 > frame && key.interpolation_type != InterpolationType::HSD_A_OP_SLP {
-                    break
-                }
-                op_intrp = key.interpolation_type;
-            }
-            return AnimState {p0, p1, d0, d1, t0, t1, op_intrp, op};
+                cursor.state = Some(state);
+                value
+            },
         }
     }
+}
 
-    pub fn get_value(&self, frame: f32) -> f32 {
-        let anim_state = self.get_anim_state(frame);
-        if frame == anim_state.t0 {
-            anim_state.p0
-        } else if  frame == anim_state.t1 {
-            anim_state.p1
-        } else if anim_state.t0 == anim_state.t1 || anim_state.op_intrp == InterpolationType::HSD_A_OP_CON || anim_state.op_intrp == InterpolationType::HSD_A_OP_KEY {
-            anim_state.p0
+impl AnimState {
+    // Value at the start of the segment
+    pub fn p0(&self) -> f32 {
+        self.p0
+    }
+
+    // Value at the end of the segment
+    pub fn p1(&self) -> f32 {
+        self.p1
+    }
+
+    // Slope leaving the start of the segment, per frame
+    pub fn d0(&self) -> f32 {
+        self.d0
+    }
+
+    // Slope entering the end of the segment, per frame
+    pub fn d1(&self) -> f32 {
+        self.d1
+    }
+
+    pub fn t0(&self) -> f32 {
+        self.t0
+    }
+
+    pub fn t1(&self) -> f32 {
+        self.t1
+    }
+
+    // Interpolation of the segment, the type of its start key
+    pub fn interpolation_type(&self) -> InterpolationType {
+        self.op_intrp
+    }
+
+    // Type of the key that ends the segment
+    pub fn end_interpolation_type(&self) -> InterpolationType {
+        self.op
+    }
+
+    pub fn value(&self, frame: f32) -> f32 {
+        if frame == self.t0 {
+            self.p0
+        } else if  frame == self.t1 {
+            self.p1
+        } else if self.t0 == self.t1 || self.op_intrp == InterpolationType::HSD_A_OP_CON || self.op_intrp == InterpolationType::HSD_A_OP_KEY {
+            self.p0
         } else {
-            let frame_diff = frame - anim_state.t0;
-            let weight = frame_diff / (anim_state.t1 - anim_state.t0);
+            let frame_diff = frame - self.t0;
+            let weight = frame_diff / (self.t1 - self.t0);
 
-            match anim_state.op_intrp {
-                InterpolationType::HSD_A_OP_LIN => lerp_interpolation(anim_state.p0, anim_state.p1, weight),
-                _ => hermite_spline_interpolation(1. / (anim_state.t1 - anim_state.t0), frame_diff, anim_state.p0, anim_state.p1, anim_state.d0, anim_state.d1)
+            match self.op_intrp {
+                InterpolationType::HSD_A_OP_LIN => lerp_interpolation(self.p0, self.p1, weight),
+                _ => hermite_spline_interpolation(1. / (self.t1 - self.t0), frame_diff, self.p0, self.p1, self.d0, self.d1)
             }
         }
     }
@@ -269,62 +397,47 @@ impl Animation {
         }
     }
 
-    pub fn from_smd_path(path: &str) -> Result<Self, ParseSMDError> {
+    pub fn from_smd_path(path: &str) -> Result<Self, Error> {
         Ok(Self::from_model(Model::from_smd_path(path)?))
     }
 
-    pub fn from_smd_bytes(bytes: &[u8]) -> Result<Self, ParseSMDError> {
+    pub fn from_smd_bytes(bytes: &[u8]) -> Result<Self, Error> {
         Ok(Self::from_model(Model::from_smd_bytes(bytes)?))
     }
 
-    pub fn load_figatree_from_path(&mut self, path: &str) -> Result<(), ParseFigaTreeError> {
-        let file = File::open(&path)?;
-        let reader = BufReader::new(file);
-        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
-        self.load_figatree(&lines)
+    pub fn from_smd_source(source: Source) -> Result<Self, Error> {
+        Ok(Self::from_model(Model::from_smd_source(source)?))
     }
 
-    pub fn load_figatree_from_bytes(&mut self, bytes: &[u8]) -> Result<(), ParseFigaTreeError> {
-        let reader = Cursor::new(bytes);
-        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
-        self.load_figatree(&lines)
+    pub fn load_figatree_from_path(&mut self, path: &str) -> Result<Vec<Diagnostic>, Error> {
+        let file = File::open(path).map_err(|e| Error::io(Input::Animation, e))?;
+        self.load_figatree_from_reader(BufReader::new(file), ParseMode::Lenient)
     }
 
-    pub fn load_figatree(&mut self, lines: &Vec<String>) -> Result<(), ParseFigaTreeError> {
-        let mut current_bone = -1;
-        let mut current_track_type = TrackType::HSD_A_J_NONE;
-    
-        let mut tracks: Vec<Track> = vec![];
-        let mut keys: Vec<Key> = vec![];
-    
-        for line in lines {
-            let line = &line.trim().to_string();
-            if line.starts_with("FrameCount: ") {
-                self.frame_count = f32::from_str(&line.replace("FrameCount: ", ""))?;
-            }
-            else if line.starts_with("Node ") {
-                if current_bone >= 0 {
-                    if let Some(bone_index) = self.model.indexes.get(&current_bone) {
-                        let mut bone = &mut self.model.bones[*bone_index];
-                        bone.tracks = tracks.clone();
-                    }
-                }
-                current_bone = i32::from_str(&line.replace("Node ", "").replace(":", ""))?;
-                tracks = vec![];
-            } else if line.starts_with("HSD_A_J_") {
-                current_track_type = Track::parse_type(&line)?;
-                keys = vec![];
-            } else if line.contains("HSD_A_OP") {
-                let mut key = Key::from_str(&line)?;
-                if key.frame > self.frame_count {
-                    key.frame = self.frame_count;
-                }
-                keys.push(key);
-            } else if line == "}" {
-                tracks.push(Track{r#type: current_track_type, keys: keys.clone()})
-            }
+    pub fn load_figatree_from_bytes(&mut self, bytes: &[u8]) -> Result<Vec<Diagnostic>, Error> {
+        self.load_figatree_from_reader(Cursor::new(bytes), ParseMode::Lenient)
+    }
+
+    pub fn load_figatree_from_source(&mut self, source: Source, mode: ParseMode) -> Result<Vec<Diagnostic>, Error> {
+        self.load_figatree_from_reader(source.reader(Input::Animation)?, mode)
+    }
+
+    pub fn load_figatree_from_reader<R: BufRead>(&mut self, reader: R, mode: ParseMode) -> Result<Vec<Diagnostic>, Error> {
+        let mut loader = FigatreeLoader::new(self, mode);
+        crate::read_lines(reader, Input::Animation, |number, line| loader.feed(number, line).map(|_| true))?;
+        loader.finish()
+    }
+
+    pub fn load_figatree(&mut self, lines: &[String]) -> Result<Vec<Diagnostic>, Error> {
+        self.load_figatree_with_mode(lines, ParseMode::Lenient)
+    }
+
+    pub fn load_figatree_with_mode(&mut self, lines: &[String], mode: ParseMode) -> Result<Vec<Diagnostic>, Error> {
+        let mut loader = FigatreeLoader::new(self, mode);
+        for (number, line) in lines.iter().enumerate() {
+            loader.feed(number + 1, line)?;
         }
-        Ok(())
+        loader.finish()
     }
 
     pub fn get_frame_model(&self, frame: f32) -> Model {
@@ -333,14 +446,96 @@ impl Animation {
         anim_model
     }
 
+    pub fn write_figatree<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writeln!(writer, "FrameCount: {}", self.frame_count)?;
+        for bone in &self.model.bones {
+            writeln!(writer, "Node {}:", bone.index)?;
+            for track in &bone.tracks {
+                writeln!(writer, "{}", track.r#type)?;
+                writeln!(writer, "{{")?;
+                for key in &track.keys {
+                    writeln!(writer, "\t{}", key)?;
+                }
+                writeln!(writer, "}}")?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write_smd<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writeln!(writer, "version 1")?;
+        writeln!(writer, "nodes")?;
+        for bone in &self.model.bones {
+            writeln!(writer, "{} \"{}\" {}", bone.index, bone.name, bone.parent)?;
+        }
+        writeln!(writer, "end")?;
+        writeln!(writer, "skeleton")?;
+        let mut model = self.model.clone();
+        let frames = (self.frame_count.ceil() as usize).max(1);
+        for frame in 0..frames {
+            model.update_joints(frame as f32);
+            writeln!(writer, "time {}", frame)?;
+            for bone in &model.bones {
+                let j = &bone.joint;
+                writeln!(writer, "{} {} {} {} {} {} {}", bone.index, j.tx, j.ty, j.tz, j.rx, j.ry, j.rz)?;
+            }
+        }
+        writeln!(writer, "end")
+    }
+
     pub fn attach_hurtboxes(&mut self, hurtboxes: Vec<Hurtbox>) {
         self.hurtboxes = hurtboxes;
         // TODO: trim bones to fix only hurboxes bone_index
     }
 
+    // Poses `scratch`, a copy of this animation's model, without allocating.
+    pub fn pose_into(&self, frame: f32, scratch: &mut Model) {
+        for (bone, pose) in self.model.bones.iter().zip(scratch.bones.iter_mut()) {
+            for track in &bone.tracks {
+                if let Some(component) = pose.joint.component_mut(track.r#type) {
+                    *component = track.get_value(frame);
+                }
+            }
+        }
+        scratch.update_transforms(scratch.root_bone_index, None);
+    }
+
+    // Writes the world hurtboxes at `frame` into `hurtboxes` and returns how many there are,
+    // which can exceed the slice length. Hurtboxes on bones missing from the model are skipped.
+    pub fn query_hurtboxes(&self, frame: f32, scratch: &mut Model, hurtboxes: &mut [WorldHurtbox]) -> usize {
+        self.pose_into(frame, scratch);
+        let mut count = 0;
+        for hb in &self.hurtboxes {
+            if let Some(index) = scratch.indexes.get(&hb.bone_index) {
+                if let Some(output) = hurtboxes.get_mut(count) {
+                    *output = hb.world(&scratch.bones[*index].transform());
+                }
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // Same as `query_hurtboxes`, reusing the capacity of `hurtboxes`.
+    pub fn query_hurtboxes_into(&self, frame: f32, scratch: &mut Model, hurtboxes: &mut Vec<WorldHurtbox>) {
+        self.pose_into(frame, scratch);
+        hurtboxes.clear();
+        for hb in &self.hurtboxes {
+            if let Some(index) = scratch.indexes.get(&hb.bone_index) {
+                hurtboxes.push(hb.world(&scratch.bones[*index].transform()));
+            }
+        }
+    }
+
     pub fn get_frame_hurtboxes_2d(&self, frame: f32) -> Vec<(Point2<f32>, Point2<f32>, f32)>{
         let model = self.get_frame_model(frame);
         let mut hurtboxes_2d: Vec<(Point2<f32>, Point2<f32>, f32)> = vec![];
+        self.hurtboxes_2d(&model, &mut hurtboxes_2d);
+        hurtboxes_2d
+    }
+
+    // Projects the hurtboxes of a posed copy of the model, appending to `hurtboxes_2d`.
+    pub(crate) fn hurtboxes_2d(&self, model: &Model, hurtboxes_2d: &mut Vec<(Point2<f32>, Point2<f32>, f32)>) {
         for hb in &self.hurtboxes {
             if let Some(index) = model.indexes.get(&hb.bone_index) {
                 let bone = &model.bones[*index];
@@ -349,7 +544,6 @@ impl Animation {
                 hurtboxes_2d.push((Point2{x: hbc1.z, y: hbc1.y}, Point2{x: hbc2.z, y: hbc2.y}, hb.size))
             }
         }
-        hurtboxes_2d
     }
 }
 
@@ -365,3 +559,111 @@ fn hermite_spline_interpolation(fterm: f32, time: f32, p0: f32, p1: f32, d0: f32
     fvar2 = 2. * fvar2 * fterm;
     d1 * fvar4 + d0 * (time + (fvar4 - fvar1 * fterm)) + p0 * (1. + (fvar2 - fvar3)) + p1 * (-fvar2 + fvar3)
 }
+
+// Line by line state of a figatree being loaded into an animation.
+struct FigatreeLoader<'a> {
+    animation: &'a mut Animation,
+    mode: ParseMode,
+    current_node: Option<i32>,
+    current_track_type: TrackType,
+    node_count: usize,
+    tracks: Vec<Track>,
+    keys: Vec<Key>,
+    errors: Vec<Error>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> FigatreeLoader<'a> {
+    fn new(animation: &'a mut Animation, mode: ParseMode) -> Self {
+        FigatreeLoader {
+            animation,
+            mode,
+            current_node: None,
+            current_track_type: TrackType::HSD_A_J_NONE,
+            node_count: 0,
+            tracks: vec![],
+            keys: vec![],
+            errors: vec![],
+            diagnostics: vec![],
+        }
+    }
+
+    fn assign_tracks(&mut self) {
+        let model = &mut self.animation.model;
+        if let Some(&bone_index) = self.current_node.and_then(|node| model.indexes.get(&node)) {
+            model.bones[bone_index].tracks = std::mem::take(&mut self.tracks);
+        }
+        self.tracks.clear();
+    }
+
+    fn feed(&mut self, line_number: usize, line: &str) -> Result<(), Error> {
+        let node = self.current_node.unwrap_or(-1);
+        for statement in figatree::statements(line) {
+            match statement {
+                Ok(Statement::FrameCount(frame_count)) => self.animation.frame_count = frame_count,
+                Ok(Statement::Node(bone)) => {
+                    self.assign_tracks();
+                    if !self.animation.model.indexes.contains_key(&bone) {
+                        self.diagnostics.push(Diagnostic::UnknownNode {line: line_number, node: bone});
+                    }
+                    self.node_count += 1;
+                    self.current_node = Some(bone);
+                },
+                Ok(Statement::Track(track_type)) => {
+                    if self.tracks.iter().any(|t| t.r#type == track_type) {
+                        self.diagnostics.push(Diagnostic::DuplicateTrack {line: line_number, node, track: track_type});
+                    }
+                    self.keys.clear();
+                    self.current_track_type = track_type;
+                },
+                Ok(Statement::Key(mut key)) => {
+                    let track = self.current_track_type;
+                    let frame_count = self.animation.frame_count;
+                    if let Some(previous) = self.keys.last() {
+                        if key.frame < previous.frame {
+                            self.diagnostics.push(Diagnostic::KeyOutOfOrder {line: line_number, node, track, frame: key.frame, previous: previous.frame});
+                        }
+                    }
+                    let follows_key = self.keys.last().is_some_and(|k| k.interpolation_type != InterpolationType::HSD_A_OP_SLP);
+                    if key.interpolation_type == InterpolationType::HSD_A_OP_SLP && !follows_key {
+                        self.diagnostics.push(Diagnostic::StraySlope {line: line_number, node, track});
+                    }
+                    if key.frame > frame_count {
+                        self.diagnostics.push(Diagnostic::KeyClamped {line: line_number, node, track, frame: key.frame, frame_count});
+                        key.frame = frame_count;
+                    }
+                    self.keys.push(key);
+                },
+                Ok(Statement::Close) => {
+                    if self.keys.last().map(|k| k.interpolation_type) == Some(InterpolationType::HSD_A_OP_SLP) {
+                        self.diagnostics.push(Diagnostic::StraySlope {line: line_number, node, track: self.current_track_type});
+                    }
+                    self.tracks.push(Track{r#type: self.current_track_type, keys: std::mem::take(&mut self.keys)});
+                },
+                Ok(Statement::Open) => (),
+                Err(error) => {
+                    let error = error.at_line(line_number);
+                    match (self.mode, &error) {
+                        (ParseMode::Lenient, Error::Parse {cause: Cause::UnexpectedLine, ..}) => (),
+                        (ParseMode::Lenient, _) => return Err(error),
+                        (ParseMode::Strict, _) => self.errors.push(error),
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Vec<Diagnostic>, Error> {
+        self.assign_tracks();
+        let bones = self.animation.model.bones.len();
+        if self.node_count != bones {
+            self.diagnostics.push(Diagnostic::NodeCountMismatch {nodes: self.node_count, bones});
+        }
+        if self.errors.is_empty() {
+            Ok(self.diagnostics)
+        } else {
+            Err(Error::Multiple {input: Input::Animation, errors: self.errors})
+        }
+    }
+}
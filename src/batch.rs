@@ -0,0 +1,104 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use nalgebra::geometry::Isometry3;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::animation::Animation;
+use crate::bone::Model;
+use crate::frames::update_joints;
+use crate::hurtbox::WorldHurtbox;
+
+// Jobs share their animation through an `Arc`, so several jobs can cover frames of the same
+// animation and be handed to other threads.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Animation>();
+    assert_send_sync::<Model>();
+};
+
+// Frames of one animation to evaluate.
+#[derive(Clone)]
+pub struct Job {
+    pub animation: Arc<Animation>,
+    pub frames: Range<usize>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BatchSizeError {
+    pub bones: usize,
+    pub hurtboxes: usize,
+}
+
+impl Job {
+    pub fn new(animation: Arc<Animation>, frames: Range<usize>) -> Self {
+        Job {animation, frames}
+    }
+
+    pub fn bones_len(&self) -> usize {
+        self.frames.len() * self.animation.model.bones.len()
+    }
+
+    // Like `query_hurtboxes`, hurtboxes on bones missing from the model are skipped.
+    pub fn hurtboxes_len(&self) -> usize {
+        self.frames.len() * self.hurtbox_count()
+    }
+
+    fn hurtbox_count(&self) -> usize {
+        let model = &self.animation.model;
+        self.animation.hurtboxes.iter().filter(|hurtbox| model.indexes.contains_key(&hurtbox.bone_index)).count()
+    }
+
+    // Writes the world transform of every bone, then of every hurtbox, frame after frame.
+    fn run(&self, bones: &mut [Isometry3<f32>], hurtboxes: &mut [WorldHurtbox]) {
+        let animation = &*self.animation;
+        let mut model = animation.model.clone();
+        let mut cursors = model.bones.iter().map(|bone| bone.tracks.iter().map(|track| track.cursor()).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let bone_count = model.bones.len();
+        let hurtbox_count = self.hurtbox_count();
+        for (i, frame) in self.frames.clone().enumerate() {
+            update_joints(&mut model, &mut cursors, frame as f32);
+            model.update_transforms(model.root_bone_index, None);
+            for (output, bone) in bones[i * bone_count..(i + 1) * bone_count].iter_mut().zip(&model.bones) {
+                *output = bone.transform();
+            }
+            let posed = animation.hurtboxes.iter().filter_map(|hurtbox| Some(hurtbox.world(&model.bones[*model.indexes.get(&hurtbox.bone_index)?].transform())));
+            for (output, hurtbox) in hurtboxes[i * hurtbox_count..(i + 1) * hurtbox_count].iter_mut().zip(posed) {
+                *output = hurtbox;
+            }
+        }
+    }
+}
+
+// Sizes of the `bones` and `hurtboxes` slices `evaluate` expects for these jobs.
+pub fn output_len(jobs: &[Job]) -> (usize, usize) {
+    jobs.iter().fold((0, 0), |(bones, hurtboxes), job| (bones + job.bones_len(), hurtboxes + job.hurtboxes_len()))
+}
+
+fn split<'o, T>(jobs: &[Job], mut output: &'o mut [T], len: impl Fn(&Job) -> usize) -> Vec<&'o mut [T]> {
+    let mut chunks = Vec::with_capacity(jobs.len());
+    for job in jobs {
+        let (chunk, rest) = std::mem::take(&mut output).split_at_mut(len(job));
+        chunks.push(chunk);
+        output = rest;
+    }
+    chunks
+}
+
+// Evaluates every job into the output slices, job after job, in parallel when the `rayon`
+// feature is enabled. Results are identical either way.
+pub fn evaluate(jobs: &[Job], bones: &mut [Isometry3<f32>], hurtboxes: &mut [WorldHurtbox]) -> Result<(), BatchSizeError> {
+    let (bones_len, hurtboxes_len) = output_len(jobs);
+    if bones.len() != bones_len || hurtboxes.len() != hurtboxes_len {
+        return Err(BatchSizeError {bones: bones_len, hurtboxes: hurtboxes_len});
+    }
+    let bone_chunks = split(jobs, bones, Job::bones_len);
+    let hurtbox_chunks = split(jobs, hurtboxes, Job::hurtboxes_len);
+
+    #[cfg(feature = "rayon")]
+    jobs.par_iter().zip(bone_chunks).zip(hurtbox_chunks).for_each(|((job, bones), hurtboxes)| job.run(bones, hurtboxes));
+    #[cfg(not(feature = "rayon"))]
+    jobs.iter().zip(bone_chunks).zip(hurtbox_chunks).for_each(|((job, bones), hurtboxes)| job.run(bones, hurtboxes));
+    Ok(())
+}
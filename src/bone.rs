@@ -5,8 +5,11 @@ use std::collections::BTreeMap;
 use nalgebra::geometry::{UnitQuaternion, Translation3, Isometry3};
 
 use crate::animation::{Track, TrackType};
+use crate::error::{parse_f32, parse_i32, Cause, Error, Input};
+use crate::Source;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Joint {
     pub tx: f32,
     pub ty: f32,
@@ -17,74 +20,26 @@ pub struct Joint {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bone {
     pub index: i32,
     pub parent: i32,
     pub childs: Vec<i32>,
     pub name: String,
     pub joint: Joint,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub transform: Option<Isometry3<f32>>,
     pub tracks: Vec<Track>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Model {
     pub bones: Vec<Bone>,
     pub indexes: BTreeMap<i32,usize>,
     pub root_bone_index: i32,
 }
 
-#[derive(Debug, Clone)]
-pub struct ParseBoneError;
-
-impl From<std::num::ParseIntError> for ParseBoneError {
-    fn from(_: std::num::ParseIntError) -> Self {
-        ParseBoneError{}
-    }
-}
-
-impl From<std::num::ParseFloatError> for ParseBoneError {
-    fn from(_: std::num::ParseFloatError) -> Self {
-        ParseBoneError{}
-    }
-}
-
-#[derive(Debug, Clone)]
-pub struct ParseJointError;
-
-impl From<std::num::ParseIntError> for ParseJointError {
-    fn from(_: std::num::ParseIntError) -> Self {
-        ParseJointError{}
-    }
-}
-
-impl From<std::num::ParseFloatError> for ParseJointError {
-    fn from(_: std::num::ParseFloatError) -> Self {
-        ParseJointError{}
-    }
-}
-
-#[derive(Debug, Clone)]
-pub struct ParseSMDError;
-
-impl From<ParseJointError> for ParseSMDError {
-    fn from(_: ParseJointError) -> Self {
-        ParseSMDError{}
-    }
-}
-
-impl From<ParseBoneError> for ParseSMDError {
-    fn from(_: ParseBoneError) -> Self {
-        ParseSMDError{}
-    }
-}
-
-impl From<std::io::Error> for ParseSMDError {
-    fn from(_: std::io::Error) -> Self {
-        ParseSMDError{}
-    }
-}
-
 impl Joint {
     pub fn new() -> Joint {
         Joint {
@@ -97,19 +52,32 @@ impl Joint {
         }
     }
     
-    pub fn parse(s: &str) -> Result<(i32, Joint), ParseJointError> {
+    pub fn parse(s: &str) -> Result<(i32, Joint), Error> {
         let joint = s.split(" ").collect::<Vec<_>>();
         if joint.len() == 7 {
-            let index = i32::from_str(joint[0])?;
-            let tx = f32::from_str(joint[1])?;
-            let ty = f32::from_str(joint[2])?;
-            let tz = f32::from_str(joint[3])?;
-            let rx = f32::from_str(joint[4])?;
-            let ry = f32::from_str(joint[5])?;
-            let rz = f32::from_str(joint[6])?;
+            let index = parse_i32(Input::Model, joint[0])?;
+            let tx = parse_f32(Input::Model, joint[1])?;
+            let ty = parse_f32(Input::Model, joint[2])?;
+            let tz = parse_f32(Input::Model, joint[3])?;
+            let rx = parse_f32(Input::Model, joint[4])?;
+            let ry = parse_f32(Input::Model, joint[5])?;
+            let rz = parse_f32(Input::Model, joint[6])?;
             return Ok((index, Joint {tx, ty, tz, rx, ry, rz}));
         }
-        return Err(ParseJointError);
+        Err(Error::parse(Input::Model, s, Cause::FieldCount {expected: 7, found: joint.len()}))
+    }
+
+    // Component a track of this type animates, none for the types that don't move joints.
+    pub(crate) fn component_mut(&mut self, r#type: TrackType) -> Option<&mut f32> {
+        match r#type {
+            TrackType::HSD_A_J_ROTX => Some(&mut self.rx),
+            TrackType::HSD_A_J_ROTY => Some(&mut self.ry),
+            TrackType::HSD_A_J_ROTZ => Some(&mut self.rz),
+            TrackType::HSD_A_J_TRAX => Some(&mut self.tx),
+            TrackType::HSD_A_J_TRAY => Some(&mut self.ty),
+            TrackType::HSD_A_J_TRAZ => Some(&mut self.tz),
+            _ => None,
+        }
     }
 }
 
@@ -161,72 +129,50 @@ impl Bone {
 }
 
 impl FromStr for Bone {
-    type Err = ParseBoneError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let index_name_parent = s.split(" ").collect::<Vec<_>>();
         if index_name_parent.len() == 3 {
-            let index = i32::from_str(index_name_parent[0])?;
-            let parent = i32::from_str(index_name_parent[2])?;
+            let index = parse_i32(Input::Model, index_name_parent[0])?;
+            let parent = parse_i32(Input::Model, index_name_parent[2])?;
             let name = index_name_parent[1].replace("\"", "").trim().to_string();
             return Ok(Bone::new(index, parent, name));
         }
-        return Err(ParseBoneError);
+        Err(Error::parse(Input::Model, s, Cause::FieldCount {expected: 3, found: index_name_parent.len()}))
     }
 }
 
 
 impl Model {
-    pub fn from_smd_path(path: &str) -> Result<Self, ParseSMDError> {
-        let file = File::open(&path)?;
-        let reader = BufReader::new(file);
-        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
-        Self::from_smd(&lines)
+    pub fn from_smd_path(path: &str) -> Result<Self, Error> {
+        let file = File::open(path).map_err(|e| Error::io(Input::Model, e))?;
+        Self::from_smd_reader(BufReader::new(file))
     }
     
-    pub fn from_smd_bytes(bytes: &[u8]) -> Result<Self, ParseSMDError> {
-        let reader = Cursor::new(bytes);
-        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
-        Self::from_smd(&lines)
+    pub fn from_smd_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_smd_reader(Cursor::new(bytes))
+    }
+
+    pub fn from_smd_source(source: Source) -> Result<Self, Error> {
+        Self::from_smd_reader(source.reader(Input::Model)?)
+    }
+
+    // Stops reading at the end of the skeleton section.
+    pub fn from_smd_reader<R: BufRead>(reader: R) -> Result<Self, Error> {
+        let mut parser = SmdParser::default();
+        crate::read_lines(reader, Input::Model, |number, line| parser.feed(number, line))?;
+        Ok(parser.finish())
     }
     
-    pub fn from_smd(lines: &Vec<String>) -> Result<Self, ParseSMDError> {
-        let mut nodes_flag = false;
-        let mut skeleton_flag = false;
-    
-        let mut bones: Vec<Bone> = vec![];
-    
-        for line in lines {
-            let line = &line.trim().to_string();
-    
-            if line.contains("nodes") {
-                nodes_flag = true;
-            }
-            if line.contains("skeleton") {
-                skeleton_flag = true;
-            }
-            if nodes_flag && line.split(" ").count() == 3 {
-                &bones.push(Bone::from_str(&line)?);
-            }
-            if skeleton_flag && line.split(" ").count() == 7 {
-                let (index, joint) = Joint::parse(&line)?;
-                for mut bone in &mut bones {
-                    if bone.index == index {
-                        bone.joint = joint;
-                    }
-                }
-            }
-        }
-        let indexes = Self::make_index(&bones);
-        let mut root_bone_index = 0;
-        for bone in &bones {
-            if bone.parent == -1 {
-                root_bone_index = bone.index;
+    pub fn from_smd(lines: &[String]) -> Result<Self, Error> {
+        let mut parser = SmdParser::default();
+        for (number, line) in lines.iter().enumerate() {
+            if !parser.feed(number + 1, line)? {
+                break;
             }
         }
-        let mut model = Model {bones, indexes, root_bone_index};
-        model.compute_childs();
-        Ok(model)
+        Ok(parser.finish())
     }
 
     pub fn make_index(bones: &Vec<Bone>) -> BTreeMap<i32, usize> {
@@ -247,20 +193,18 @@ impl Model {
     }
 
     pub fn update_transforms(&mut self, bone_index: i32, parent_index: Option<i32>) {
-        let bone = &self.bones[self.indexes[&bone_index]];
-        let mut local_transform = bone.local_transform();
-        if let Some(index) = parent_index {
-            let parent = &self.bones[self.indexes[&index]];
+        let index = self.indexes[&bone_index];
+        let mut local_transform = self.bones[index].local_transform();
+        if let Some(parent_index) = parent_index {
+            let parent = &self.bones[self.indexes[&parent_index]];
             if let Some(parent_transform) = parent.transform {
                 local_transform = parent_transform * local_transform;
             } 
         }
-        let mut bone = &mut self.bones[self.indexes[&bone_index]];
-        bone.transform = Some(local_transform.clone());
-        // println!("{:?}, {:?}", bone_index, parent_index);
-        // println!("{:?}", bone.transform);
-        for child_index in &bone.childs.clone() {
-            self.update_transforms(*child_index, Some(bone_index));
+        self.bones[index].transform = Some(local_transform);
+        for child in 0..self.bones[index].childs.len() {
+            let child_index = self.bones[index].childs[child];
+            self.update_transforms(child_index, Some(bone_index));
         }
     }
 
@@ -271,14 +215,8 @@ impl Model {
     pub fn update_joints(&mut self, frame: f32) {
         for bone in &mut self.bones {
             for track in &bone.tracks {
-                match track.r#type {
-                    TrackType::HSD_A_J_ROTX => bone.joint.rx = track.get_value(frame),
-                    TrackType::HSD_A_J_ROTY => bone.joint.ry = track.get_value(frame),
-                    TrackType::HSD_A_J_ROTZ => bone.joint.rz = track.get_value(frame),
-                    TrackType::HSD_A_J_TRAX => bone.joint.tx = track.get_value(frame),
-                    TrackType::HSD_A_J_TRAY => bone.joint.ty = track.get_value(frame),
-                    TrackType::HSD_A_J_TRAZ => bone.joint.tz = track.get_value(frame),
-                    _ => (),
+                if let Some(component) = bone.joint.component_mut(track.r#type) {
+                    *component = track.get_value(frame);
                 }
             }
         }
@@ -289,3 +227,62 @@ impl Model {
         self.update_transforms(self.root_bone_index, None);
     }
 }
+
+#[derive(Default)]
+struct SmdParser {
+    nodes_flag: bool,
+    skeleton_flag: bool,
+    bones: Vec<Bone>,
+}
+
+impl SmdParser {
+    // Returns false once there is nothing left to read.
+    fn feed(&mut self, line_number: usize, line: &str) -> Result<bool, Error> {
+        let line = line.trim();
+
+        if line.contains("nodes") {
+            self.nodes_flag = true;
+        }
+        if line.contains("skeleton") {
+            self.skeleton_flag = true;
+        }
+        // The nodes section ends before the skeleton one, whose lines can have three fields too
+        if self.nodes_flag && !self.skeleton_flag && line == "end" {
+            self.nodes_flag = false;
+        }
+        if self.nodes_flag && line.split(" ").count() == 3 {
+            let bone = Bone::from_str(line).map_err(|e| e.at_line(line_number))?;
+            if self.bones.iter().any(|other| other.index == bone.index) {
+                return Err(Error::parse(Input::Model, line, Cause::DuplicateBone).at_line(line_number));
+            }
+            self.bones.push(bone);
+        }
+        // Every time block overwrites the joints, the last one is the reference pose
+        if self.skeleton_flag && line == "end" {
+            return Ok(false);
+        }
+        if self.skeleton_flag && line.split(" ").count() == 7 {
+            let (index, joint) = Joint::parse(line).map_err(|e| e.at_line(line_number))?;
+            for bone in &mut self.bones {
+                if bone.index == index {
+                    bone.joint = joint;
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn finish(self) -> Model {
+        let bones = self.bones;
+        let indexes = Model::make_index(&bones);
+        let mut root_bone_index = 0;
+        for bone in &bones {
+            if bone.parent == -1 {
+                root_bone_index = bone.index;
+            }
+        }
+        let mut model = Model {bones, indexes, root_bone_index};
+        model.compute_childs();
+        model
+    }
+}
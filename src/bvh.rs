@@ -0,0 +1,128 @@
+use std::io::Write;
+use nalgebra::geometry::UnitQuaternion;
+
+use crate::animation::{Animation, TrackType};
+use crate::bone::Model;
+
+const FRAME_TIME: f32 = 1. / 60.;
+
+// Axes are listed in BVH channel order, the first one being the outermost rotation:
+// `ZXY` writes `Zrotation Xrotation Yrotation` for R = Rz * Rx * Ry.
+// `ZYX` is the convention used by `Bone::local_rotation`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RotationOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl RotationOrder {
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            RotationOrder::XYZ => [0, 1, 2],
+            RotationOrder::XZY => [0, 2, 1],
+            RotationOrder::YXZ => [1, 0, 2],
+            RotationOrder::YZX => [1, 2, 0],
+            RotationOrder::ZXY => [2, 0, 1],
+            RotationOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    // Angles in radians, in the same order as `axes`.
+    pub fn angles(&self, rotation: &UnitQuaternion<f32>) -> [f32; 3] {
+        let m = rotation.to_rotation_matrix().into_inner();
+        let [i, a1, _] = self.axes();
+        let odd = (i + 1) % 3 != a1;
+        let (j, k) = if odd { ((i + 2) % 3, (i + 1) % 3) } else { ((i + 1) % 3, (i + 2) % 3) };
+
+        let mut res = [0.; 3];
+        res[0] = m[(j, k)].atan2(m[(k, k)]);
+        let c2 = m[(i, i)].hypot(m[(i, j)]);
+        res[1] = (-m[(i, k)]).atan2(c2);
+        let (s1, c1) = res[0].sin_cos();
+        res[2] = (s1 * m[(k, i)] - c1 * m[(j, i)]).atan2(c1 * m[(j, j)] - s1 * m[(k, j)]);
+        if !odd {
+            res = [-res[0], -res[1], -res[2]];
+        }
+        res
+    }
+
+    fn channels(&self) -> String {
+        let names = ["Xrotation", "Yrotation", "Zrotation"];
+        let axes = self.axes();
+        format!("{} {} {}", names[axes[0]], names[axes[1]], names[axes[2]])
+    }
+}
+
+fn has_translation(model: &Model, bone: usize) -> bool {
+    let bone = &model.bones[bone];
+    !model.indexes.contains_key(&bone.parent) || bone.tracks.iter().any(|t| matches!(t.r#type,
+        TrackType::HSD_A_J_TRAX | TrackType::HSD_A_J_TRAY | TrackType::HSD_A_J_TRAZ))
+}
+
+fn joint_name(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join("_")
+}
+
+fn write_joint<W: Write>(writer: &mut W, model: &Model, bone: usize, depth: usize, order: RotationOrder, hierarchy: &mut Vec<usize>) -> std::io::Result<()> {
+    let indent = "\t".repeat(depth);
+    let b = &model.bones[bone];
+    let keyword = if depth == 0 { "ROOT" } else { "JOINT" };
+    writeln!(writer, "{}{} {}", indent, keyword, joint_name(&b.name))?;
+    writeln!(writer, "{}{{", indent)?;
+    writeln!(writer, "{}\tOFFSET {} {} {}", indent, b.joint.tx, b.joint.ty, b.joint.tz)?;
+    if has_translation(model, bone) {
+        writeln!(writer, "{}\tCHANNELS 6 Xposition Yposition Zposition {}", indent, order.channels())?;
+    } else {
+        writeln!(writer, "{}\tCHANNELS 3 {}", indent, order.channels())?;
+    }
+    hierarchy.push(bone);
+    let childs = b.childs.iter().filter_map(|c| model.indexes.get(c).cloned()).collect::<Vec<_>>();
+    if childs.is_empty() {
+        writeln!(writer, "{}\tEnd Site", indent)?;
+        writeln!(writer, "{}\t{{", indent)?;
+        writeln!(writer, "{}\t\tOFFSET 0 0 0", indent)?;
+        writeln!(writer, "{}\t}}", indent)?;
+    }
+    for child in childs {
+        write_joint(writer, model, child, depth + 1, order, hierarchy)?;
+    }
+    writeln!(writer, "{}}}", indent)
+}
+
+impl Animation {
+    pub fn write_bvh<W: Write>(&self, writer: &mut W, order: RotationOrder) -> std::io::Result<()> {
+        let model = &self.model;
+        let mut hierarchy = vec![];
+        writeln!(writer, "HIERARCHY")?;
+        for (i, bone) in model.bones.iter().enumerate() {
+            if !model.indexes.contains_key(&bone.parent) {
+                write_joint(writer, model, i, 0, order, &mut hierarchy)?;
+            }
+        }
+
+        let frames = (self.frame_count.ceil() as usize).max(1);
+        writeln!(writer, "MOTION")?;
+        writeln!(writer, "Frames: {}", frames)?;
+        writeln!(writer, "Frame Time: {}", FRAME_TIME)?;
+        let mut pose = model.clone();
+        let mut values: Vec<String> = vec![];
+        for frame in 0..frames {
+            pose.update_joints(frame as f32);
+            values.clear();
+            for &bone in &hierarchy {
+                let b = &pose.bones[bone];
+                if has_translation(model, bone) {
+                    values.extend([b.joint.tx, b.joint.ty, b.joint.tz].iter().map(|v| v.to_string()));
+                }
+                let angles = order.angles(&b.local_rotation());
+                values.extend(angles.iter().map(|a| a.to_degrees().to_string()));
+            }
+            writeln!(writer, "{}", values.join(" "))?;
+        }
+        Ok(())
+    }
+}
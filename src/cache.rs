@@ -0,0 +1,421 @@
+use std::fmt;
+use std::io::Write;
+
+use crate::animation::{Animation, InterpolationType, Key, Track, TrackType};
+use crate::bone::{Bone, Joint, Model};
+use crate::hurtbox::{Hurtbox, HurtboxType};
+
+// Little-endian layout:
+//   header   magic, version u32, source hash u64, payload size u32, payload checksum u32
+//   payload  model, hurtboxes, animations
+// Keys are fixed size records that `CachedTrack::keys` decodes one at a time from the borrowed
+// bytes. Building an `Animation` copies them into its tracks.
+pub const MAGIC: [u8; 4] = *b"MANC";
+pub const VERSION: u32 = 1;
+
+const HEADER_SIZE: usize = 24;
+const KEY_SIZE: usize = 13;
+
+const TRACK_TYPES: [TrackType; 12] = [
+    TrackType::HSD_A_J_NONE,
+    TrackType::HSD_A_J_ROTX,
+    TrackType::HSD_A_J_ROTY,
+    TrackType::HSD_A_J_ROTZ,
+    TrackType::HSD_A_J_PATH,
+    TrackType::HSD_A_J_TRAX,
+    TrackType::HSD_A_J_TRAY,
+    TrackType::HSD_A_J_TRAZ,
+    TrackType::HSD_A_J_SCAX,
+    TrackType::HSD_A_J_SCAY,
+    TrackType::HSD_A_J_SCAZ,
+    TrackType::HSD_A_J_NODE,
+];
+
+const INTERPOLATION_TYPES: [InterpolationType; 7] = [
+    InterpolationType::HSD_A_OP_NONE,
+    InterpolationType::HSD_A_OP_CON,
+    InterpolationType::HSD_A_OP_LIN,
+    InterpolationType::HSD_A_OP_SPL0,
+    InterpolationType::HSD_A_OP_SPL,
+    InterpolationType::HSD_A_OP_SLP,
+    InterpolationType::HSD_A_OP_KEY,
+];
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CacheError {
+    BadMagic,
+    UnsupportedVersion(u32),
+    Truncated,
+    ChecksumMismatch,
+    InvalidData,
+}
+
+// A validated cache borrowing the bytes it was read from.
+pub struct Cache<'a> {
+    source_hash: u64,
+    model: &'a [u8],
+    hurtboxes: &'a [u8],
+    animations: Vec<CachedAnimation<'a>>,
+}
+
+pub struct CachedAnimation<'a> {
+    pub name: &'a str,
+    pub frame_count: f32,
+    bones: &'a [u8],
+}
+
+pub struct CachedTrack<'a> {
+    pub bone_index: i32,
+    pub r#type: TrackType,
+    keys: &'a [u8],
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CacheError::BadMagic => f.write_str("not an animation cache"),
+            CacheError::UnsupportedVersion(version) => write!(f, "unsupported cache version {}", version),
+            CacheError::Truncated => f.write_str("cache data is truncated"),
+            CacheError::ChecksumMismatch => f.write_str("cache checksum does not match"),
+            CacheError::InvalidData => f.write_str("cache contains invalid data"),
+        }
+    }
+}
+
+impl std::error::Error for CacheError {}
+
+fn fnv1a_32(data: &[u8]) -> u32 {
+    data.iter().fold(0x811c9dc5, |hash, byte| (hash ^ *byte as u32).wrapping_mul(0x01000193))
+}
+
+fn fnv1a_64(hash: u64, data: &[u8]) -> u64 {
+    data.iter().fold(hash, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
+
+// Hash of the text sources a cache was built from, stored in the header to detect stale caches.
+pub fn source_hash(sources: &[&[u8]]) -> u64 {
+    sources.iter().fold(0xcbf29ce484222325, |hash, source| {
+        let hash = fnv1a_64(hash, &(source.len() as u64).to_le_bytes());
+        fnv1a_64(hash, source)
+    })
+}
+
+fn hurtbox_type_code(r#type: &HurtboxType) -> u8 {
+    match r#type {
+        HurtboxType::Low => 0,
+        HurtboxType::Mid => 1,
+        HurtboxType::High => 2,
+    }
+}
+
+fn write_u32(data: &mut Vec<u8>, value: u32) {
+    data.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_i32(data: &mut Vec<u8>, value: i32) {
+    data.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_f32(data: &mut Vec<u8>, value: f32) {
+    data.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_str(data: &mut Vec<u8>, value: &str) {
+    write_u32(data, value.len() as u32);
+    data.extend_from_slice(value.as_bytes());
+}
+
+fn write_section(data: &mut Vec<u8>, section: &[u8]) {
+    write_u32(data, section.len() as u32);
+    data.extend_from_slice(section);
+}
+
+fn encode_model(model: &Model) -> Vec<u8> {
+    let mut data = vec![];
+    write_i32(&mut data, model.root_bone_index);
+    write_u32(&mut data, model.bones.len() as u32);
+    for bone in &model.bones {
+        write_i32(&mut data, bone.index);
+        write_i32(&mut data, bone.parent);
+        write_str(&mut data, &bone.name);
+        let joint = &bone.joint;
+        for value in &[joint.tx, joint.ty, joint.tz, joint.rx, joint.ry, joint.rz] {
+            write_f32(&mut data, *value);
+        }
+    }
+    data
+}
+
+fn encode_hurtboxes(hurtboxes: &[Hurtbox]) -> Vec<u8> {
+    let mut data = vec![];
+    write_u32(&mut data, hurtboxes.len() as u32);
+    for hurtbox in hurtboxes {
+        write_i32(&mut data, hurtbox.bone_index);
+        for value in &[hurtbox.x1, hurtbox.y1, hurtbox.z1, hurtbox.x2, hurtbox.y2, hurtbox.z2, hurtbox.size] {
+            write_f32(&mut data, *value);
+        }
+        data.push(hurtbox_type_code(&hurtbox.r#type));
+        data.push(hurtbox.grabable as u8);
+    }
+    data
+}
+
+fn encode_tracks(animation: &Animation) -> Vec<u8> {
+    let mut data = vec![];
+    let tracks = animation.model.bones.iter().flat_map(|bone| bone.tracks.iter().map(move |track| (bone.index, track)));
+    write_u32(&mut data, tracks.clone().count() as u32);
+    for (bone_index, track) in tracks {
+        write_i32(&mut data, bone_index);
+        data.push(TRACK_TYPES.iter().position(|t| *t == track.r#type).unwrap_or(0) as u8);
+        write_u32(&mut data, track.keys.len() as u32);
+        for key in &track.keys {
+            write_f32(&mut data, key.frame());
+            write_f32(&mut data, key.value());
+            write_f32(&mut data, key.tan());
+            data.push(INTERPOLATION_TYPES.iter().position(|t| *t == key.interpolation_type()).unwrap_or(0) as u8);
+        }
+    }
+    data
+}
+
+pub fn write_cache<W: Write>(writer: &mut W, source_hash: u64, model: &Model, hurtboxes: &[Hurtbox], animations: &[(&str, &Animation)]) -> std::io::Result<()> {
+    let mut payload = vec![];
+    write_section(&mut payload, &encode_model(model));
+    write_section(&mut payload, &encode_hurtboxes(hurtboxes));
+    write_u32(&mut payload, animations.len() as u32);
+    for (name, animation) in animations {
+        write_str(&mut payload, name);
+        write_f32(&mut payload, animation.frame_count);
+        write_section(&mut payload, &encode_tracks(animation));
+    }
+
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    writer.write_all(&source_hash.to_le_bytes())?;
+    writer.write_all(&(payload.len() as u32).to_le_bytes())?;
+    writer.write_all(&fnv1a_32(&payload).to_le_bytes())?;
+    writer.write_all(&payload)
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Reader {data, offset: 0}
+    }
+
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], CacheError> {
+        let end = self.offset.checked_add(count).ok_or(CacheError::Truncated)?;
+        let bytes = self.data.get(self.offset..end).ok_or(CacheError::Truncated)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], CacheError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self) -> Result<u8, CacheError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, CacheError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, CacheError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn i32(&mut self) -> Result<i32, CacheError> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    fn f32(&mut self) -> Result<f32, CacheError> {
+        Ok(f32::from_le_bytes(self.array()?))
+    }
+
+    fn str(&mut self) -> Result<&'a str, CacheError> {
+        let len = self.u32()? as usize;
+        std::str::from_utf8(self.bytes(len)?).map_err(|_| CacheError::InvalidData)
+    }
+
+    fn section(&mut self) -> Result<&'a [u8], CacheError> {
+        let len = self.u32()? as usize;
+        self.bytes(len)
+    }
+}
+
+impl<'a> Cache<'a> {
+    // Checks the header and checksum and indexes the animations without decoding them.
+    pub fn read(data: &'a [u8]) -> Result<Self, CacheError> {
+        let mut header = Reader::new(data);
+        if header.bytes(4)? != MAGIC {
+            return Err(CacheError::BadMagic);
+        }
+        let version = header.u32()?;
+        if version != VERSION {
+            return Err(CacheError::UnsupportedVersion(version));
+        }
+        let source_hash = header.u64()?;
+        let payload_size = header.u32()? as usize;
+        let checksum = header.u32()?;
+        let payload = &data[HEADER_SIZE..];
+        if payload.len() < payload_size {
+            return Err(CacheError::Truncated);
+        }
+        let payload = &payload[..payload_size];
+        if fnv1a_32(payload) != checksum {
+            return Err(CacheError::ChecksumMismatch);
+        }
+
+        let mut reader = Reader::new(payload);
+        let model = reader.section()?;
+        let hurtboxes = reader.section()?;
+        let count = reader.u32()?;
+        let mut animations = vec![];
+        for _ in 0..count {
+            let name = reader.str()?;
+            let frame_count = reader.f32()?;
+            let bones = reader.section()?;
+            animations.push(CachedAnimation {name, frame_count, bones});
+        }
+        Ok(Cache {source_hash, model, hurtboxes, animations})
+    }
+
+    pub fn source_hash(&self) -> u64 {
+        self.source_hash
+    }
+
+    // False when the sources changed since the cache was written.
+    pub fn is_fresh(&self, source_hash: u64) -> bool {
+        self.source_hash == source_hash
+    }
+
+    pub fn animations(&self) -> &[CachedAnimation<'a>] {
+        &self.animations
+    }
+
+    pub fn find(&self, name: &str) -> Option<&CachedAnimation<'a>> {
+        self.animations.iter().find(|animation| animation.name == name)
+    }
+
+    pub fn model(&self) -> Result<Model, CacheError> {
+        let mut reader = Reader::new(self.model);
+        let root_bone_index = reader.i32()?;
+        let count = reader.u32()?;
+        let mut bones = vec![];
+        for _ in 0..count {
+            let index = reader.i32()?;
+            let parent = reader.i32()?;
+            let mut bone = Bone::new(index, parent, reader.str()?.to_string());
+            bone.joint = Joint {
+                tx: reader.f32()?,
+                ty: reader.f32()?,
+                tz: reader.f32()?,
+                rx: reader.f32()?,
+                ry: reader.f32()?,
+                rz: reader.f32()?,
+            };
+            bones.push(bone);
+        }
+        let indexes = Model::make_index(&bones);
+        if indexes.len() != bones.len() {
+            return Err(CacheError::InvalidData);
+        }
+        let mut model = Model {bones, indexes, root_bone_index};
+        model.compute_childs();
+        Ok(model)
+    }
+
+    pub fn hurtboxes(&self) -> Result<Vec<Hurtbox>, CacheError> {
+        let mut reader = Reader::new(self.hurtboxes);
+        let count = reader.u32()?;
+        let mut hurtboxes = vec![];
+        for _ in 0..count {
+            hurtboxes.push(Hurtbox {
+                bone_index: reader.i32()?,
+                x1: reader.f32()?,
+                y1: reader.f32()?,
+                z1: reader.f32()?,
+                x2: reader.f32()?,
+                y2: reader.f32()?,
+                z2: reader.f32()?,
+                size: reader.f32()?,
+                r#type: match reader.u8()? {
+                    0 => HurtboxType::Low,
+                    1 => HurtboxType::Mid,
+                    2 => HurtboxType::High,
+                    _ => return Err(CacheError::InvalidData),
+                },
+                grabable: reader.u8()? != 0,
+            });
+        }
+        Ok(hurtboxes)
+    }
+
+    // Decodes an animation with its own copy of the model and hurtboxes.
+    pub fn animation(&self, animation: &CachedAnimation) -> Result<Animation, CacheError> {
+        let mut anim = Animation::from_model(self.model()?);
+        anim.frame_count = animation.frame_count;
+        for track in animation.tracks() {
+            let track = track?;
+            let bone_index = *anim.model.indexes.get(&track.bone_index).ok_or(CacheError::InvalidData)?;
+            let keys = track.keys().collect::<Result<Vec<_>, _>>()?;
+            anim.model.bones[bone_index].tracks.push(Track {r#type: track.r#type, keys});
+        }
+        anim.attach_hurtboxes(self.hurtboxes()?);
+        Ok(anim)
+    }
+}
+
+impl<'a> CachedAnimation<'a> {
+    pub fn tracks(&self) -> impl Iterator<Item = Result<CachedTrack<'a>, CacheError>> {
+        let mut reader = Reader::new(self.bones);
+        let count = reader.u32();
+        let mut remaining = *count.as_ref().unwrap_or(&0);
+        let mut error = count.err();
+        std::iter::from_fn(move || {
+            if let Some(error) = error.take() {
+                return Some(Err(error));
+            }
+            if remaining == 0 {
+                return None;
+            }
+            remaining -= 1;
+            let track = (|| {
+                let bone_index = reader.i32()?;
+                let r#type = *TRACK_TYPES.get(reader.u8()? as usize).ok_or(CacheError::InvalidData)?;
+                let count = reader.u32()? as usize;
+                let keys = reader.bytes(count.checked_mul(KEY_SIZE).ok_or(CacheError::Truncated)?)?;
+                Ok(CachedTrack {bone_index, r#type, keys})
+            })();
+            if track.is_err() {
+                remaining = 0;
+            }
+            Some(track)
+        })
+    }
+}
+
+impl<'a> CachedTrack<'a> {
+    pub fn key_count(&self) -> usize {
+        self.keys.len() / KEY_SIZE
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = Result<Key, CacheError>> + 'a {
+        self.keys.chunks_exact(KEY_SIZE).map(|record| {
+            let mut reader = Reader::new(record);
+            let frame = reader.f32()?;
+            let value = reader.f32()?;
+            let tan = reader.f32()?;
+            let interpolation_type = *INTERPOLATION_TYPES.get(reader.u8()? as usize).ok_or(CacheError::InvalidData)?;
+            Ok(Key::new(frame, value, tan, interpolation_type))
+        })
+    }
+}
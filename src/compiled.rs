@@ -0,0 +1,357 @@
+use crate::animation::{AnimState, Animation, TrackType};
+use crate::bone::{Joint, Model};
+use crate::curve::Mode;
+
+// Tracks flattened into segments stored as parallel arrays. A track's state only changes at
+// frame 0, at its key frames and at its last frame, so each interval between two of those is
+// one segment holding the state `Track::get_anim_state` returns anywhere inside it.
+//
+// Evaluating a frame looks up the active segment of every channel, gathers the segments by
+// mode into `Lanes`, evaluates each mode four lanes at a time, and scatters the values back
+// to the joints. The arithmetic is the same sequence of operations as `AnimState::value`, so
+// results are bit-identical to `Track::get_value`.
+#[derive(Debug, Clone)]
+pub struct CompiledAnimation {
+    pub frame_count: f32,
+    channels: Vec<Channel>,
+    segments: Segments,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Channel {
+    bone: usize,
+    component: Component,
+    first_segment: usize,
+    segment_count: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Component {
+    TX,
+    TY,
+    TZ,
+    RX,
+    RY,
+    RZ,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Segments {
+    start: Vec<f32>,
+    mode: Vec<Mode>,
+    t0: Vec<f32>,
+    t1: Vec<f32>,
+    p0: Vec<f32>,
+    p1: Vec<f32>,
+    d0: Vec<f32>,
+    d1: Vec<f32>,
+    fterm: Vec<f32>,
+}
+
+// Segments gathered for one mode, one lane per channel.
+#[derive(Debug, Clone, Default)]
+struct Group {
+    channels: Vec<usize>,
+    t0: Vec<f32>,
+    t1: Vec<f32>,
+    p0: Vec<f32>,
+    p1: Vec<f32>,
+    d0: Vec<f32>,
+    d1: Vec<f32>,
+    fterm: Vec<f32>,
+    values: Vec<f32>,
+}
+
+// Scratch buffers for `CompiledAnimation::evaluate`, reused between frames.
+#[derive(Debug, Clone, Default)]
+pub struct Lanes {
+    constant: Group,
+    linear: Group,
+    hermite: Group,
+}
+
+fn component(r#type: TrackType) -> Option<Component> {
+    match r#type {
+        TrackType::HSD_A_J_TRAX => Some(Component::TX),
+        TrackType::HSD_A_J_TRAY => Some(Component::TY),
+        TrackType::HSD_A_J_TRAZ => Some(Component::TZ),
+        TrackType::HSD_A_J_ROTX => Some(Component::RX),
+        TrackType::HSD_A_J_ROTY => Some(Component::RY),
+        TrackType::HSD_A_J_ROTZ => Some(Component::RZ),
+        _ => None,
+    }
+}
+
+fn set(joint: &mut Joint, component: Component, value: f32) {
+    match component {
+        Component::TX => joint.tx = value,
+        Component::TY => joint.ty = value,
+        Component::TZ => joint.tz = value,
+        Component::RX => joint.rx = value,
+        Component::RY => joint.ry = value,
+        Component::RZ => joint.rz = value,
+    }
+}
+
+impl Segments {
+    fn push(&mut self, start: f32, state: &AnimState) {
+        let (p0, p1, d0, d1, t0, t1) = (state.p0(), state.p1(), state.d0(), state.d1(), state.t0(), state.t1());
+        self.start.push(start);
+        self.mode.push(state.mode());
+        self.t0.push(t0);
+        self.t1.push(t1);
+        self.p0.push(p0);
+        self.p1.push(p1);
+        self.d0.push(d0);
+        self.d1.push(d1);
+        self.fterm.push(1. / (t1 - t0));
+    }
+}
+
+impl Group {
+    fn clear(&mut self) {
+        self.channels.clear();
+        self.t0.clear();
+        self.t1.clear();
+        self.p0.clear();
+        self.p1.clear();
+        self.d0.clear();
+        self.d1.clear();
+        self.fterm.clear();
+        self.values.clear();
+    }
+
+    fn push(&mut self, channel: usize, segments: &Segments, segment: usize) {
+        self.channels.push(channel);
+        self.t0.push(segments.t0[segment]);
+        self.t1.push(segments.t1[segment]);
+        self.p0.push(segments.p0[segment]);
+        self.p1.push(segments.p1[segment]);
+        self.d0.push(segments.d0[segment]);
+        self.d1.push(segments.d1[segment]);
+        self.fterm.push(segments.fterm[segment]);
+    }
+}
+
+impl Animation {
+    pub fn compile(&self) -> CompiledAnimation {
+        let mut channels: Vec<Channel> = vec![];
+        let mut segments = Segments::default();
+        for (bone_slot, bone) in self.model.bones.iter().enumerate() {
+            for track in &bone.tracks {
+                let component = match component(track.r#type) {
+                    Some(component) => component,
+                    None => continue,
+                };
+                // A later track of the same type overrides an earlier one, as in `Model::update_joints`
+                channels.retain(|c| c.bone != bone_slot || c.component != component);
+                let first_segment = segments.start.len();
+                let breakpoints = track.breakpoints();
+                segments.push(f32::NEG_INFINITY, &track.get_anim_state(f32::NEG_INFINITY));
+                for breakpoint in breakpoints {
+                    segments.push(breakpoint, &track.get_anim_state(breakpoint));
+                }
+                let segment_count = segments.start.len() - first_segment;
+                channels.push(Channel {bone: bone_slot, component, first_segment, segment_count});
+            }
+        }
+        CompiledAnimation {frame_count: self.frame_count, channels, segments}
+    }
+}
+
+impl CompiledAnimation {
+    pub fn channel_count(&self) -> usize {
+        self.channels.len()
+    }
+
+    pub fn lanes(&self) -> Lanes {
+        let mut lanes = Lanes::default();
+        for group in [&mut lanes.constant, &mut lanes.linear, &mut lanes.hermite] {
+            group.channels.reserve(self.channels.len());
+            group.values.reserve(self.channels.len());
+        }
+        lanes
+    }
+
+    fn segment(&self, channel: &Channel, frame: f32) -> usize {
+        let starts = &self.segments.start[channel.first_segment..channel.first_segment + channel.segment_count];
+        channel.first_segment + starts.partition_point(|start| *start <= frame).saturating_sub(1)
+    }
+
+    fn evaluate_with<F: FnMut(usize, Component, f32)>(&self, frame: f32, lanes: &mut Lanes, mut write: F) {
+        lanes.constant.clear();
+        lanes.linear.clear();
+        lanes.hermite.clear();
+        for (index, channel) in self.channels.iter().enumerate() {
+            let segment = self.segment(channel, frame);
+            let group = match self.segments.mode[segment] {
+                Mode::Constant => &mut lanes.constant,
+                Mode::Linear => &mut lanes.linear,
+                Mode::Hermite => &mut lanes.hermite,
+            };
+            group.push(index, &self.segments, segment);
+        }
+        evaluate_constant(frame, &mut lanes.constant);
+        evaluate_linear(frame, &mut lanes.linear);
+        evaluate_hermite(frame, &mut lanes.hermite);
+        for group in [&lanes.constant, &lanes.linear, &lanes.hermite] {
+            for (channel, value) in group.channels.iter().zip(&group.values) {
+                let channel = &self.channels[*channel];
+                write(channel.bone, channel.component, *value);
+            }
+        }
+    }
+
+    // Writes every animated joint component at `frame`. `joints` follows the order of the
+    // model's bones; components without a track are left untouched.
+    pub fn evaluate(&self, frame: f32, lanes: &mut Lanes, joints: &mut [Joint]) {
+        self.evaluate_with(frame, lanes, |bone, component, value| {
+            if let Some(joint) = joints.get_mut(bone) {
+                set(joint, component, value);
+            }
+        });
+    }
+
+    // Poses `scratch`, a copy of the compiled animation's model.
+    pub fn pose_into(&self, frame: f32, lanes: &mut Lanes, scratch: &mut Model) {
+        self.evaluate_with(frame, lanes, |bone, component, value| {
+            if let Some(bone) = scratch.bones.get_mut(bone) {
+                set(&mut bone.joint, component, value);
+            }
+        });
+        scratch.update_transforms(scratch.root_bone_index, None);
+    }
+}
+
+fn select(frame: f32, t0: f32, t1: f32, p0: f32, p1: f32, value: f32) -> f32 {
+    if frame == t0 {
+        p0
+    } else if frame == t1 {
+        p1
+    } else {
+        value
+    }
+}
+
+fn linear(frame: f32, t0: f32, t1: f32, p0: f32, p1: f32) -> f32 {
+    let weight = (frame - t0) / (t1 - t0);
+    p0 * (1. - weight) + p1 * weight
+}
+
+fn hermite(frame: f32, t0: f32, fterm: f32, p0: f32, p1: f32, d0: f32, d1: f32) -> f32 {
+    let time = frame - t0;
+    let fvar1 = time * time;
+    let mut fvar2 = fterm * fterm * time * fvar1;
+    let fvar3 = 3. * fvar1 * fterm * fterm;
+    let fvar4 = fvar2 - fvar1 * fterm;
+    fvar2 = 2. * fvar2 * fterm;
+    d1 * fvar4 + d0 * (time + (fvar4 - fvar1 * fterm)) + p0 * (1. + (fvar2 - fvar3)) + p1 * (-fvar2 + fvar3)
+}
+
+fn evaluate_constant(frame: f32, group: &mut Group) {
+    for i in 0..group.channels.len() {
+        group.values.push(select(frame, group.t0[i], group.t1[i], group.p0[i], group.p1[i], group.p0[i]));
+    }
+}
+
+fn evaluate_linear(frame: f32, group: &mut Group) {
+    let count = group.channels.len();
+    group.values.resize(count, 0.);
+    let done = simd::linear(frame, group);
+    for i in done..count {
+        let value = linear(frame, group.t0[i], group.t1[i], group.p0[i], group.p1[i]);
+        group.values[i] = select(frame, group.t0[i], group.t1[i], group.p0[i], group.p1[i], value);
+    }
+}
+
+fn evaluate_hermite(frame: f32, group: &mut Group) {
+    let count = group.channels.len();
+    group.values.resize(count, 0.);
+    let done = simd::hermite(frame, group);
+    for i in done..count {
+        let value = hermite(frame, group.t0[i], group.fterm[i], group.p0[i], group.p1[i], group.d0[i], group.d1[i]);
+        group.values[i] = select(frame, group.t0[i], group.t1[i], group.p0[i], group.p1[i], value);
+    }
+}
+
+// SSE versions of `linear` and `hermite`. They return how many leading lanes they filled,
+// the rest is left to the scalar code.
+#[cfg(target_arch = "x86_64")]
+mod simd {
+    use std::arch::x86_64::*;
+
+    use super::Group;
+
+    // `frame == t0` gives `p0`, else `frame == t1` gives `p1`, else `value`.
+    unsafe fn select(frame: __m128, t0: __m128, t1: __m128, p0: __m128, p1: __m128, value: __m128) -> __m128 {
+        let at_t1 = _mm_cmpeq_ps(frame, t1);
+        let value = _mm_or_ps(_mm_and_ps(at_t1, p1), _mm_andnot_ps(at_t1, value));
+        let at_t0 = _mm_cmpeq_ps(frame, t0);
+        _mm_or_ps(_mm_and_ps(at_t0, p0), _mm_andnot_ps(at_t0, value))
+    }
+
+    pub fn linear(frame: f32, group: &mut Group) -> usize {
+        let count = group.channels.len() / 4 * 4;
+        // SSE2 is part of the x86_64 baseline.
+        unsafe {
+            let frame = _mm_set1_ps(frame);
+            let one = _mm_set1_ps(1.);
+            for i in (0..count).step_by(4) {
+                let t0 = _mm_loadu_ps(group.t0[i..].as_ptr());
+                let t1 = _mm_loadu_ps(group.t1[i..].as_ptr());
+                let p0 = _mm_loadu_ps(group.p0[i..].as_ptr());
+                let p1 = _mm_loadu_ps(group.p1[i..].as_ptr());
+                let weight = _mm_div_ps(_mm_sub_ps(frame, t0), _mm_sub_ps(t1, t0));
+                let value = _mm_add_ps(_mm_mul_ps(p0, _mm_sub_ps(one, weight)), _mm_mul_ps(p1, weight));
+                _mm_storeu_ps(group.values[i..].as_mut_ptr(), select(frame, t0, t1, p0, p1, value));
+            }
+        }
+        count
+    }
+
+    pub fn hermite(frame: f32, group: &mut Group) -> usize {
+        let count = group.channels.len() / 4 * 4;
+        unsafe {
+            let frame = _mm_set1_ps(frame);
+            let one = _mm_set1_ps(1.);
+            let two = _mm_set1_ps(2.);
+            let three = _mm_set1_ps(3.);
+            for i in (0..count).step_by(4) {
+                let t0 = _mm_loadu_ps(group.t0[i..].as_ptr());
+                let t1 = _mm_loadu_ps(group.t1[i..].as_ptr());
+                let p0 = _mm_loadu_ps(group.p0[i..].as_ptr());
+                let p1 = _mm_loadu_ps(group.p1[i..].as_ptr());
+                let d0 = _mm_loadu_ps(group.d0[i..].as_ptr());
+                let d1 = _mm_loadu_ps(group.d1[i..].as_ptr());
+                let fterm = _mm_loadu_ps(group.fterm[i..].as_ptr());
+                let time = _mm_sub_ps(frame, t0);
+                let fvar1 = _mm_mul_ps(time, time);
+                let fvar2 = _mm_mul_ps(_mm_mul_ps(_mm_mul_ps(fterm, fterm), time), fvar1);
+                let fvar3 = _mm_mul_ps(_mm_mul_ps(_mm_mul_ps(three, fvar1), fterm), fterm);
+                let fvar1_fterm = _mm_mul_ps(fvar1, fterm);
+                let fvar4 = _mm_sub_ps(fvar2, fvar1_fterm);
+                let fvar2 = _mm_mul_ps(_mm_mul_ps(two, fvar2), fterm);
+                let value = _mm_mul_ps(d1, fvar4);
+                let value = _mm_add_ps(value, _mm_mul_ps(d0, _mm_add_ps(time, _mm_sub_ps(fvar4, fvar1_fterm))));
+                let value = _mm_add_ps(value, _mm_mul_ps(p0, _mm_add_ps(one, _mm_sub_ps(fvar2, fvar3))));
+                let value = _mm_add_ps(value, _mm_mul_ps(p1, _mm_sub_ps(fvar3, fvar2)));
+                _mm_storeu_ps(group.values[i..].as_mut_ptr(), select(frame, t0, t1, p0, p1, value));
+            }
+        }
+        count
+    }
+}
+
+// Other targets evaluate every lane with the scalar code.
+#[cfg(not(target_arch = "x86_64"))]
+mod simd {
+    use super::Group;
+
+    pub fn linear(_frame: f32, _group: &mut Group) -> usize {
+        0
+    }
+
+    pub fn hermite(_frame: f32, _group: &mut Group) -> usize {
+        0
+    }
+}
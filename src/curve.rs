@@ -0,0 +1,205 @@
+use std::fmt;
+
+use crate::animation::{AnimState, InterpolationType, Track};
+
+// A track is piecewise: its state only changes at frame 0, at its key frames and at its last
+// frame. Each `Segment` covers one interval between two of those frames, `start` included and
+// `end` excluded, and holds the `AnimState` that `Track::get_value` uses inside it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Segment {
+    pub start: f32,
+    pub end: f32,
+    pub state: AnimState,
+    // Index of the key that closes the segment, none past the last key
+    pub end_key: Option<usize>,
+    // SLP key just before `end_key` that provided one of the slopes
+    pub slope: Option<Slope>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Slope {
+    pub key: usize,
+    pub tangent: Tangent,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Tangent {
+    // Before a spline key, an SLP key replaces the start slope `d0`
+    Outgoing,
+    // Before a linear or constant key, an SLP key sets the end slope `d1`
+    Incoming,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Mode {
+    Constant,
+    Linear,
+    Hermite,
+}
+
+// Which rule of `AnimState::value` produced a value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Rule {
+    AtStart,
+    AtEnd,
+    Held,
+    Linear { weight: f32 },
+    Hermite { time: f32, length: f32 },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Explanation {
+    pub frame: f32,
+    pub value: f32,
+    pub rule: Rule,
+    pub segment: Segment,
+}
+
+impl AnimState {
+    pub fn mode(&self) -> Mode {
+        let op_intrp = self.interpolation_type();
+        if self.t0() == self.t1() || op_intrp == InterpolationType::HSD_A_OP_CON || op_intrp == InterpolationType::HSD_A_OP_KEY {
+            Mode::Constant
+        } else if op_intrp == InterpolationType::HSD_A_OP_LIN {
+            Mode::Linear
+        } else {
+            Mode::Hermite
+        }
+    }
+}
+
+impl Segment {
+    pub fn mode(&self) -> Mode {
+        self.state.mode()
+    }
+
+    pub fn contains(&self, frame: f32) -> bool {
+        self.start <= frame && frame < self.end
+    }
+
+    pub fn value(&self, frame: f32) -> f32 {
+        self.state.value(frame)
+    }
+
+    pub fn rule(&self, frame: f32) -> Rule {
+        let state = &self.state;
+        if frame == state.t0() {
+            Rule::AtStart
+        } else if frame == state.t1() {
+            Rule::AtEnd
+        } else {
+            match self.mode() {
+                Mode::Constant => Rule::Held,
+                Mode::Linear => Rule::Linear {weight: (frame - state.t0()) / (state.t1() - state.t0())},
+                Mode::Hermite => Rule::Hermite {time: frame - state.t0(), length: state.t1() - state.t0()},
+            }
+        }
+    }
+}
+
+impl Track {
+    // Frames where the state of the track can change, sorted and deduplicated.
+    pub fn breakpoints(&self) -> Vec<f32> {
+        let (last_frame, _) = self.last_frame();
+        let mut breakpoints = self.keys.iter().map(|k| k.frame()).chain([0., last_frame]).collect::<Vec<_>>();
+        breakpoints.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        breakpoints.dedup();
+        breakpoints
+    }
+
+    // Index of the key `get_anim_state` stops at for `frame`.
+    fn end_key(&self, frame: f32) -> Option<usize> {
+        let (last_frame, _) = self.last_frame();
+        if self.keys.len() > 1 && frame >= last_frame {
+            return None;
+        }
+        let mut t1 = 0.;
+        for (index, key) in self.keys.iter().enumerate() {
+            match key.interpolation_type() {
+                InterpolationType::HSD_A_OP_CON | InterpolationType::HSD_A_OP_LIN
+                | InterpolationType::HSD_A_OP_SPL0 | InterpolationType::HSD_A_OP_SPL => t1 = key.frame(),
+                _ => (),
+            }
+            if t1 > frame && key.interpolation_type() != InterpolationType::HSD_A_OP_SLP {
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    fn segment(&self, start: f32, end: f32) -> Segment {
+        let state = self.get_anim_state(start);
+        let end_key = self.end_key(start);
+        let slope = end_key.filter(|index| *index > 0).and_then(|index| {
+            let key = index - 1;
+            if self.keys[key].interpolation_type() != InterpolationType::HSD_A_OP_SLP {
+                return None;
+            }
+            let tangent = match self.keys[index].interpolation_type() {
+                InterpolationType::HSD_A_OP_CON | InterpolationType::HSD_A_OP_LIN => Tangent::Incoming,
+                _ => Tangent::Outgoing,
+            };
+            Some(Slope {key, tangent})
+        });
+        Segment {start, end, state, end_key, slope}
+    }
+
+    // Every segment of the track in frame order, from minus to plus infinity.
+    pub fn segments(&self) -> Vec<Segment> {
+        let breakpoints = self.breakpoints();
+        let mut segments = vec![self.segment(f32::NEG_INFINITY, breakpoints[0])];
+        for (i, start) in breakpoints.iter().enumerate() {
+            let end = breakpoints.get(i + 1).copied().unwrap_or(f32::INFINITY);
+            segments.push(self.segment(*start, end));
+        }
+        segments
+    }
+
+    // Values at `from`, `from + step`, ... up to `to` included. Empty unless both bounds are
+    // finite and the step positive.
+    pub fn sample(&self, from: f32, to: f32, step: f32) -> Vec<(f32, f32)> {
+        let mut samples = vec![];
+        if !from.is_finite() || !to.is_finite() || step.is_nan() || step <= 0. {
+            return samples;
+        }
+        let mut cursor = self.cursor();
+        let mut i = 0;
+        loop {
+            let frame = from + i as f32 * step;
+            if frame > to {
+                return samples;
+            }
+            samples.push((frame, self.get_value_with_cursor(frame, &mut cursor)));
+            i += 1;
+        }
+    }
+
+    pub fn explain(&self, frame: f32) -> Explanation {
+        let segment = self.segments().into_iter().find(|s| s.contains(frame)).unwrap_or_else(|| self.segment(frame, frame));
+        Explanation {frame, value: segment.value(frame), rule: segment.rule(frame), segment}
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = &self.segment.state;
+        write!(f, "frame {} is in the {} segment [{}, {}) from {} to {}, ", self.frame, state.interpolation_type(),
+            self.segment.start, self.segment.end, state.t0(), state.t1())?;
+        match self.rule {
+            Rule::AtStart => write!(f, "at its start: value {}", self.value)?,
+            Rule::AtEnd => write!(f, "at its end: value {}", self.value)?,
+            Rule::Held => write!(f, "holding the start value {}", self.value)?,
+            Rule::Linear {weight} => write!(f, "linear from {} to {} at weight {}: value {}", state.p0(), state.p1(), weight, self.value)?,
+            Rule::Hermite {time, length} => write!(f, "hermite from {} (slope {}) to {} (slope {}) at {} of {} frames: value {}",
+                state.p0(), state.d0(), state.p1(), state.d1(), time, length, self.value)?,
+        }
+        if let Some(slope) = self.segment.slope {
+            let tangent = match slope.tangent {
+                Tangent::Outgoing => "start",
+                Tangent::Incoming => "end",
+            };
+            write!(f, ", {} slope from SLP key {}", tangent, slope.key)?;
+        }
+        Ok(())
+    }
+}
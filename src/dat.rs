@@ -0,0 +1,446 @@
+use std::fmt;
+
+use crate::animation::{Animation, InterpolationType, Key, Track, TrackType};
+
+const HEADER_SIZE: usize = 0x20;
+const FIGATREE_SIZE: usize = 0x14;
+const TRACK_SIZE: usize = 0xC;
+const ACTION_ENTRY_SIZE: usize = 0x18;
+const AJ_ALIGNMENT: usize = 0x20;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DataFormat {
+    Float,
+    Short,
+    UShort,
+    SByte,
+    Byte,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Quantisation {
+    pub format: DataFormat,
+    pub scale_exponent: u8,
+}
+
+#[derive(Debug, Clone)]
+pub struct EncodedTrack {
+    pub r#type: TrackType,
+    pub start_frame: i16,
+    pub value: Quantisation,
+    pub tan: Quantisation,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FigaTreeData {
+    pub data: Vec<u8>,
+    pub relocations: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ActionEntry {
+    pub animation_offset: u32,
+    pub animation_size: u32,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EncodeFigaTreeError {
+    EmptyTrack(TrackType),
+    // Key frames are stored as whole frame counts
+    NonIntegerFrame(f32),
+    KeyOutOfOrder { frame: f32, next: f32 },
+    // Keys that don't advance time, like SLP keys, must share the frame of the next key
+    MisplacedKey { frame: f32, next: f32 },
+    StartFrameOutOfRange(f32),
+    TrackTooLarge { r#type: TrackType, size: usize },
+    TooManyTracks { bone_index: i32, tracks: usize },
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PatchAnimationError {
+    UnknownAction(usize),
+    // A table entry or an animation lies past the end of the data
+    OffsetOutOfRange { offset: usize, len: usize },
+}
+
+impl fmt::Display for EncodeFigaTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncodeFigaTreeError::EmptyTrack(r#type) => write!(f, "{} track has no keys", r#type),
+            EncodeFigaTreeError::NonIntegerFrame(frame) => write!(f, "key frame {} is not a whole frame", frame),
+            EncodeFigaTreeError::KeyOutOfOrder {frame, next} => write!(f, "key at frame {} is followed by a key at frame {}", frame, next),
+            EncodeFigaTreeError::MisplacedKey {frame, next} => write!(f, "key at frame {} without a time must share the frame of the next key at {}", frame, next),
+            EncodeFigaTreeError::StartFrameOutOfRange(frame) => write!(f, "start frame {} does not fit in 16 bits", frame),
+            EncodeFigaTreeError::TrackTooLarge {r#type, size} => write!(f, "{} track takes {} bytes, more than 65535", r#type, size),
+            EncodeFigaTreeError::TooManyTracks {bone_index, tracks} => write!(f, "bone {} has {} tracks, more than 254", bone_index, tracks),
+        }
+    }
+}
+
+impl std::error::Error for EncodeFigaTreeError {}
+
+impl fmt::Display for PatchAnimationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatchAnimationError::UnknownAction(action) => write!(f, "no action {} in the action table", action),
+            PatchAnimationError::OffsetOutOfRange {offset, len} => write!(f, "offset {:#x} is past the end of {:#x} bytes of data", offset, len),
+        }
+    }
+}
+
+impl std::error::Error for PatchAnimationError {}
+
+impl DataFormat {
+    fn code(&self) -> u8 {
+        match self {
+            DataFormat::Float => 0,
+            DataFormat::Short => 1,
+            DataFormat::UShort => 2,
+            DataFormat::SByte => 3,
+            DataFormat::Byte => 4,
+        }
+    }
+
+    fn range(&self) -> (f32, f32) {
+        match self {
+            DataFormat::Float => (f32::MIN, f32::MAX),
+            DataFormat::Short => (i16::MIN as f32, i16::MAX as f32),
+            DataFormat::UShort => (0., u16::MAX as f32),
+            DataFormat::SByte => (i8::MIN as f32, i8::MAX as f32),
+            DataFormat::Byte => (0., u8::MAX as f32),
+        }
+    }
+}
+
+impl Quantisation {
+    pub fn float() -> Self {
+        Quantisation {format: DataFormat::Float, scale_exponent: 0}
+    }
+
+    // Smallest format, and the finest scale for it, that stores every value within tolerance.
+    pub fn choose(values: &[f32], tolerance: f32) -> Self {
+        let signed = values.iter().any(|v| *v < 0.);
+        let candidates = if signed {
+            [DataFormat::SByte, DataFormat::Short]
+        } else {
+            [DataFormat::Byte, DataFormat::UShort]
+        };
+        for format in candidates.iter() {
+            let (min, max) = format.range();
+            for scale_exponent in (0..32u8).rev() {
+                let quantisation = Quantisation {format: *format, scale_exponent};
+                let scale = quantisation.scale();
+                if values.iter().all(|v| (v * scale).round() >= min && (v * scale).round() <= max) {
+                    if values.iter().all(|v| (quantisation.quantise(*v) - v).abs() <= tolerance) {
+                        return quantisation;
+                    }
+                    break;
+                }
+            }
+        }
+        Quantisation::float()
+    }
+
+    pub fn flag(&self) -> u8 {
+        self.format.code() << 5 | self.scale_exponent
+    }
+
+    pub fn scale(&self) -> f32 {
+        (1u32 << self.scale_exponent) as f32
+    }
+
+    pub fn quantise(&self, value: f32) -> f32 {
+        match self.format {
+            DataFormat::Float => value,
+            _ => (value * self.scale()).round() / self.scale(),
+        }
+    }
+
+    fn write(&self, value: f32, data: &mut Vec<u8>) {
+        let q = (value * self.scale()).round();
+        match self.format {
+            DataFormat::Float => data.extend_from_slice(&value.to_be_bytes()),
+            DataFormat::Short => data.extend_from_slice(&(q as i16).to_be_bytes()),
+            DataFormat::UShort => data.extend_from_slice(&(q as u16).to_be_bytes()),
+            DataFormat::SByte => data.push(q as i8 as u8),
+            DataFormat::Byte => data.push(q as u8),
+        }
+    }
+}
+
+fn track_type_code(r#type: TrackType) -> u8 {
+    match r#type {
+        TrackType::HSD_A_J_NONE => 0,
+        TrackType::HSD_A_J_ROTX => 1,
+        TrackType::HSD_A_J_ROTY => 2,
+        TrackType::HSD_A_J_ROTZ => 3,
+        TrackType::HSD_A_J_PATH => 4,
+        TrackType::HSD_A_J_TRAX => 5,
+        TrackType::HSD_A_J_TRAY => 6,
+        TrackType::HSD_A_J_TRAZ => 7,
+        TrackType::HSD_A_J_SCAX => 8,
+        TrackType::HSD_A_J_SCAY => 9,
+        TrackType::HSD_A_J_SCAZ => 10,
+        TrackType::HSD_A_J_NODE => 11,
+    }
+}
+
+fn opcode(interpolation_type: InterpolationType) -> u8 {
+    match interpolation_type {
+        InterpolationType::HSD_A_OP_NONE => 0,
+        InterpolationType::HSD_A_OP_CON => 1,
+        InterpolationType::HSD_A_OP_LIN => 2,
+        InterpolationType::HSD_A_OP_SPL0 => 3,
+        InterpolationType::HSD_A_OP_SPL => 4,
+        InterpolationType::HSD_A_OP_SLP => 5,
+        InterpolationType::HSD_A_OP_KEY => 6,
+    }
+}
+
+fn has_value(interpolation_type: InterpolationType) -> bool {
+    !matches!(interpolation_type, InterpolationType::HSD_A_OP_NONE | InterpolationType::HSD_A_OP_SLP)
+}
+
+fn has_tan(interpolation_type: InterpolationType) -> bool {
+    matches!(interpolation_type, InterpolationType::HSD_A_OP_SPL | InterpolationType::HSD_A_OP_SLP)
+}
+
+fn has_time(interpolation_type: InterpolationType) -> bool {
+    matches!(interpolation_type, InterpolationType::HSD_A_OP_CON | InterpolationType::HSD_A_OP_LIN
+        | InterpolationType::HSD_A_OP_SPL0 | InterpolationType::HSD_A_OP_SPL)
+}
+
+fn write_packed(mut value: u32, data: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            data.push(byte);
+            return;
+        }
+        data.push(byte | 0x80);
+    }
+}
+
+// Opcode in the low nibble, key count - 1 in bits 4-6, then 7 more bits per continuation byte.
+fn write_opcode(interpolation_type: InterpolationType, count: usize, data: &mut Vec<u8>) {
+    let n = count as u32 - 1;
+    let mut byte = opcode(interpolation_type) | ((n & 0x7) << 4) as u8;
+    let mut rest = n >> 3;
+    if rest > 0 {
+        byte |= 0x80;
+    }
+    data.push(byte);
+    while rest > 0 {
+        let mut byte = (rest & 0x7F) as u8;
+        rest >>= 7;
+        if rest > 0 {
+            byte |= 0x80;
+        }
+        data.push(byte);
+    }
+}
+
+fn frame_delta(key: &Key, next: Option<&Key>) -> Result<u32, EncodeFigaTreeError> {
+    let delta = match next {
+        Some(next) => next.frame() - key.frame(),
+        None => 0.,
+    };
+    if delta < 0. {
+        return Err(EncodeFigaTreeError::KeyOutOfOrder {frame: key.frame(), next: key.frame() + delta});
+    }
+    if delta.fract() != 0. {
+        return Err(EncodeFigaTreeError::NonIntegerFrame(key.frame() + delta));
+    }
+    Ok(delta as u32)
+}
+
+pub fn encode_track(track: &Track, tolerance: f32) -> Result<EncodedTrack, EncodeFigaTreeError> {
+    let keys = &track.keys;
+    let first = keys.first().ok_or(EncodeFigaTreeError::EmptyTrack(track.r#type))?;
+    if first.frame().fract() != 0. {
+        return Err(EncodeFigaTreeError::NonIntegerFrame(first.frame()));
+    }
+    if first.frame().abs() > i16::MAX as f32 {
+        return Err(EncodeFigaTreeError::StartFrameOutOfRange(first.frame()));
+    }
+    let values = keys.iter().filter(|k| has_value(k.interpolation_type())).map(|k| k.value()).collect::<Vec<_>>();
+    let tans = keys.iter().filter(|k| has_tan(k.interpolation_type())).map(|k| k.tan()).collect::<Vec<_>>();
+    let value = Quantisation::choose(&values, tolerance);
+    let tan = if tans.is_empty() { Quantisation::float() } else { Quantisation::choose(&tans, tolerance) };
+
+    let mut data = vec![];
+    let mut start = 0;
+    while start < keys.len() {
+        let interpolation_type = keys[start].interpolation_type();
+        let end = keys[start..].iter()
+            .position(|k| k.interpolation_type() != interpolation_type)
+            .map_or(keys.len(), |p| start + p);
+        write_opcode(interpolation_type, end - start, &mut data);
+        for i in start..end {
+            let key = &keys[i];
+            if has_value(interpolation_type) {
+                value.write(key.value(), &mut data);
+            }
+            if has_tan(interpolation_type) {
+                tan.write(key.tan(), &mut data);
+            }
+            let delta = frame_delta(key, keys.get(i + 1))?;
+            if has_time(interpolation_type) {
+                write_packed(delta, &mut data);
+            } else if delta != 0 {
+                return Err(EncodeFigaTreeError::MisplacedKey {frame: key.frame(), next: key.frame() + delta as f32});
+            }
+        }
+        start = end;
+    }
+    if data.len() > u16::MAX as usize {
+        return Err(EncodeFigaTreeError::TrackTooLarge {r#type: track.r#type, size: data.len()});
+    }
+    Ok(EncodedTrack {r#type: track.r#type, start_frame: first.frame() as i16, value, tan, data})
+}
+
+fn align(data: &mut Vec<u8>, alignment: usize) {
+    let len = data.len().div_ceil(alignment) * alignment;
+    data.resize(len, 0);
+}
+
+fn write_u32(data: &mut [u8], offset: usize, value: u32) {
+    data[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+// Layout: FigaTree header, per-node track counts (0xFF terminated), track headers, key data.
+// Tracks without keys, such as an empty block in a figatree file, animate nothing and are left out.
+pub fn encode_figatree(animation: &Animation, tolerance: f32) -> Result<FigaTreeData, EncodeFigaTreeError> {
+    let mut counts = vec![];
+    let mut tracks = vec![];
+    for bone in &animation.model.bones {
+        let bone_tracks = bone.tracks.iter().filter(|track| !track.keys.is_empty()).collect::<Vec<_>>();
+        if bone_tracks.len() >= 0xFF {
+            return Err(EncodeFigaTreeError::TooManyTracks {bone_index: bone.index, tracks: bone_tracks.len()});
+        }
+        counts.push(bone_tracks.len() as u8);
+        for track in bone_tracks {
+            tracks.push(encode_track(track, tolerance)?);
+        }
+    }
+    counts.push(0xFF);
+
+    let mut data = vec![0; FIGATREE_SIZE];
+    let counts_offset = data.len();
+    data.extend_from_slice(&counts);
+    align(&mut data, 4);
+    let tracks_offset = data.len();
+    data.resize(tracks_offset + tracks.len() * TRACK_SIZE, 0);
+
+    let mut relocations = vec![0x0C, 0x10];
+    write_u32(&mut data, 0x00, 1);
+    data[0x08..0x0C].copy_from_slice(&animation.frame_count.to_be_bytes());
+    write_u32(&mut data, 0x0C, counts_offset as u32);
+    write_u32(&mut data, 0x10, tracks_offset as u32);
+
+    for (i, track) in tracks.iter().enumerate() {
+        let header = tracks_offset + i * TRACK_SIZE;
+        let key_offset = data.len();
+        data.extend_from_slice(&track.data);
+        align(&mut data, 4);
+        data[header..header + 2].copy_from_slice(&(track.data.len() as u16).to_be_bytes());
+        data[header + 2..header + 4].copy_from_slice(&track.start_frame.to_be_bytes());
+        data[header + 4] = track_type_code(track.r#type);
+        data[header + 5] = track.value.flag();
+        data[header + 6] = track.tan.flag();
+        write_u32(&mut data, header + 8, key_offset as u32);
+        relocations.push((header + 8) as u32);
+    }
+    Ok(FigaTreeData {data, relocations})
+}
+
+impl FigaTreeData {
+    // Standalone HSD archive with the FigaTree as its only root, as stored in PlXxAJ.dat.
+    pub fn to_dat(&self, root_name: &str) -> Vec<u8> {
+        let mut file = vec![0; HEADER_SIZE];
+        file.extend_from_slice(&self.data);
+        align(&mut file, 4);
+        let data_size = file.len() - HEADER_SIZE;
+        for relocation in &self.relocations {
+            file.extend_from_slice(&relocation.to_be_bytes());
+        }
+        file.extend_from_slice(&0u32.to_be_bytes());
+        file.extend_from_slice(&0u32.to_be_bytes());
+        file.extend_from_slice(root_name.as_bytes());
+        file.push(0);
+        let file_size = file.len();
+        write_u32(&mut file, 0x00, file_size as u32);
+        write_u32(&mut file, 0x04, data_size as u32);
+        write_u32(&mut file, 0x08, self.relocations.len() as u32);
+        write_u32(&mut file, 0x0C, 1);
+        write_u32(&mut file, 0x10, 0);
+        file
+    }
+}
+
+impl Animation {
+    pub fn encode_figatree(&self, tolerance: f32) -> Result<FigaTreeData, EncodeFigaTreeError> {
+        encode_figatree(self, tolerance)
+    }
+}
+
+// `offset` is the file offset of the fighter's action table (in PlXx.dat), `count` its number of entries.
+pub fn read_action_table(data: &[u8], offset: usize, count: usize) -> Result<Vec<ActionEntry>, PatchAnimationError> {
+    (0..count).map(|i| {
+        let entry = offset + i * ACTION_ENTRY_SIZE;
+        Ok(ActionEntry {
+            animation_offset: read_u32(data, entry + 0x04).ok_or(PatchAnimationError::OffsetOutOfRange {offset: entry + 0x04, len: data.len()})?,
+            animation_size: read_u32(data, entry + 0x08).ok_or(PatchAnimationError::OffsetOutOfRange {offset: entry + 0x08, len: data.len()})?,
+        })
+    }).collect()
+}
+
+pub fn write_action_table(data: &mut [u8], offset: usize, entries: &[ActionEntry]) -> Result<(), PatchAnimationError> {
+    let end = offset + entries.len() * ACTION_ENTRY_SIZE;
+    if end > data.len() {
+        return Err(PatchAnimationError::OffsetOutOfRange {offset: end, len: data.len()});
+    }
+    for (i, entry) in entries.iter().enumerate() {
+        let entry_offset = offset + i * ACTION_ENTRY_SIZE;
+        write_u32(data, entry_offset + 0x04, entry.animation_offset);
+        write_u32(data, entry_offset + 0x08, entry.animation_size);
+    }
+    Ok(())
+}
+
+// Replaces the animation used by `table[action]` and shifts every animation stored after it.
+// Actions sharing the replaced animation are updated too. Returns the new PlXxAJ.dat.
+pub fn replace_animation(aj: &[u8], table: &mut [ActionEntry], action: usize, animation: &[u8]) -> Result<Vec<u8>, PatchAnimationError> {
+    let old = *table.get(action).ok_or(PatchAnimationError::UnknownAction(action))?;
+    let start = old.animation_offset as usize;
+    let end = (start + old.animation_size as usize).div_ceil(AJ_ALIGNMENT) * AJ_ALIGNMENT;
+    if start + old.animation_size as usize > aj.len() {
+        return Err(PatchAnimationError::OffsetOutOfRange {offset: start + old.animation_size as usize, len: aj.len()});
+    }
+    let end = end.min(aj.len());
+
+    let mut patched = aj[..start].to_vec();
+    patched.extend_from_slice(animation);
+    align(&mut patched, AJ_ALIGNMENT);
+    let shift = patched.len() as i64 - end as i64;
+    patched.extend_from_slice(&aj[end..]);
+
+    for entry in table.iter_mut() {
+        if entry.animation_size == 0 {
+            continue;
+        }
+        if entry.animation_offset == old.animation_offset {
+            entry.animation_size = animation.len() as u32;
+        } else if entry.animation_offset > old.animation_offset {
+            entry.animation_offset = (entry.animation_offset as i64 + shift) as u32;
+        }
+    }
+    Ok(patched)
+}
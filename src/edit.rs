@@ -0,0 +1,194 @@
+use std::fmt;
+
+use crate::animation::{Animation, InterpolationType, Key, Track, TrackType};
+use crate::bone::Bone;
+
+// Keys stay sorted by frame. A key inserted at the frame of existing keys goes after them,
+// so an SLP key must be inserted before the key it shares its frame with.
+//
+// Edits only ever grow `Animation::frame_count`: an animation can run past its last key, as
+// parsed ones often do, so removing or moving keys back leaves it as it is. Set it to
+// `last_key_frame` to trim the animation to its keys.
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EditError {
+    UnknownBone(i32),
+    MissingTrack(TrackType),
+    KeyIndex(usize),
+    InvalidFrame(f32),
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EditError::UnknownBone(index) => write!(f, "no bone with index {}", index),
+            EditError::MissingTrack(r#type) => write!(f, "no {} track", r#type),
+            EditError::KeyIndex(index) => write!(f, "no key at index {}", index),
+            EditError::InvalidFrame(frame) => write!(f, "invalid key frame {}", frame),
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
+fn check_frame(frame: f32) -> Result<(), EditError> {
+    if frame.is_finite() && frame >= 0. {
+        Ok(())
+    } else {
+        Err(EditError::InvalidFrame(frame))
+    }
+}
+
+impl Track {
+    pub fn new(r#type: TrackType) -> Self {
+        Track {r#type, keys: vec![]}
+    }
+
+    pub fn is_sorted(&self) -> bool {
+        self.keys.windows(2).all(|pair| pair[0].frame() <= pair[1].frame())
+    }
+
+    // Stable sort by frame, for keys pushed by hand.
+    pub fn sort_keys(&mut self) {
+        self.keys.sort_by(|a, b| a.frame().partial_cmp(&b.frame()).unwrap_or(std::cmp::Ordering::Equal));
+    }
+
+    // Inserts after every key at or before its frame and returns its index.
+    pub fn insert_key(&mut self, key: Key) -> Result<usize, EditError> {
+        check_frame(key.frame())?;
+        let index = self.keys.partition_point(|k| k.frame() <= key.frame());
+        self.keys.insert(index, key);
+        Ok(index)
+    }
+
+    pub fn remove_key(&mut self, index: usize) -> Result<Key, EditError> {
+        if index >= self.keys.len() {
+            return Err(EditError::KeyIndex(index));
+        }
+        Ok(self.keys.remove(index))
+    }
+
+    // Moves a key to another frame and returns its new index.
+    pub fn move_key(&mut self, index: usize, frame: f32) -> Result<usize, EditError> {
+        check_frame(frame)?;
+        let key = self.remove_key(index)?;
+        self.insert_key(Key::new(frame, key.value(), key.tan(), key.interpolation_type()))
+    }
+
+    pub fn key_mut(&mut self, index: usize) -> Result<&mut Key, EditError> {
+        self.keys.get_mut(index).ok_or(EditError::KeyIndex(index))
+    }
+
+    pub fn set_interpolation_type(&mut self, index: usize, interpolation_type: InterpolationType) -> Result<(), EditError> {
+        self.key_mut(index)?.set_interpolation_type(interpolation_type);
+        Ok(())
+    }
+
+    pub fn set_value(&mut self, index: usize, value: f32) -> Result<(), EditError> {
+        self.key_mut(index)?.set_value(value);
+        Ok(())
+    }
+
+    pub fn set_tan(&mut self, index: usize, tan: f32) -> Result<(), EditError> {
+        self.key_mut(index)?.set_tan(tan);
+        Ok(())
+    }
+}
+
+impl Bone {
+    pub fn track(&self, r#type: TrackType) -> Option<&Track> {
+        self.tracks.iter().find(|t| t.r#type == r#type)
+    }
+
+    pub fn track_mut(&mut self, r#type: TrackType) -> Option<&mut Track> {
+        self.tracks.iter_mut().find(|t| t.r#type == r#type)
+    }
+
+    // Adds a track, returning the track of the same type it replaces.
+    pub fn set_track(&mut self, track: Track) -> Option<Track> {
+        match self.track_mut(track.r#type) {
+            Some(existing) => Some(std::mem::replace(existing, track)),
+            None => {
+                self.tracks.push(track);
+                None
+            },
+        }
+    }
+
+    pub fn remove_track(&mut self, r#type: TrackType) -> Option<Track> {
+        let index = self.tracks.iter().position(|t| t.r#type == r#type)?;
+        Some(self.tracks.remove(index))
+    }
+}
+
+impl Animation {
+    pub fn bone_mut(&mut self, bone_index: i32) -> Result<&mut Bone, EditError> {
+        let index = *self.model.indexes.get(&bone_index).ok_or(EditError::UnknownBone(bone_index))?;
+        Ok(&mut self.model.bones[index])
+    }
+
+    fn track_mut(&mut self, bone_index: i32, r#type: TrackType) -> Result<&mut Track, EditError> {
+        self.bone_mut(bone_index)?.track_mut(r#type).ok_or(EditError::MissingTrack(r#type))
+    }
+
+    fn extend_frame_count(&mut self, frame: f32) {
+        self.frame_count = self.frame_count.max(frame);
+    }
+
+    // Last key frame of every track, the smallest frame count that keeps all keys.
+    pub fn last_key_frame(&self) -> f32 {
+        self.model.bones.iter().flat_map(|bone| &bone.tracks).map(|track| track.last_frame().0).fold(0., f32::max)
+    }
+
+    pub fn set_track(&mut self, bone_index: i32, track: Track) -> Result<Option<Track>, EditError> {
+        for key in &track.keys {
+            check_frame(key.frame())?;
+        }
+        let (last_frame, _) = track.last_frame();
+        let mut track = track;
+        track.sort_keys();
+        let replaced = self.bone_mut(bone_index)?.set_track(track);
+        self.extend_frame_count(last_frame);
+        Ok(replaced)
+    }
+
+    pub fn remove_track(&mut self, bone_index: i32, r#type: TrackType) -> Result<Track, EditError> {
+        self.bone_mut(bone_index)?.remove_track(r#type).ok_or(EditError::MissingTrack(r#type))
+    }
+
+    // Inserts a key, creating the track if needed and extending `frame_count` to reach it.
+    pub fn insert_key(&mut self, bone_index: i32, r#type: TrackType, key: Key) -> Result<usize, EditError> {
+        let frame = key.frame();
+        let bone = self.bone_mut(bone_index)?;
+        if bone.track(r#type).is_none() {
+            check_frame(frame)?;
+            bone.tracks.push(Track::new(r#type));
+        }
+        let index = self.track_mut(bone_index, r#type)?.insert_key(key)?;
+        self.extend_frame_count(frame);
+        Ok(index)
+    }
+
+    // Leaves `frame_count` as it is, even when the removed key was the last one.
+    pub fn remove_key(&mut self, bone_index: i32, r#type: TrackType, index: usize) -> Result<Key, EditError> {
+        self.track_mut(bone_index, r#type)?.remove_key(index)
+    }
+
+    pub fn move_key(&mut self, bone_index: i32, r#type: TrackType, index: usize, frame: f32) -> Result<usize, EditError> {
+        let index = self.track_mut(bone_index, r#type)?.move_key(index, frame)?;
+        self.extend_frame_count(frame);
+        Ok(index)
+    }
+
+    pub fn set_interpolation_type(&mut self, bone_index: i32, r#type: TrackType, index: usize, interpolation_type: InterpolationType) -> Result<(), EditError> {
+        self.track_mut(bone_index, r#type)?.set_interpolation_type(index, interpolation_type)
+    }
+
+    pub fn set_tan(&mut self, bone_index: i32, r#type: TrackType, index: usize, tan: f32) -> Result<(), EditError> {
+        self.track_mut(bone_index, r#type)?.set_tan(index, tan)
+    }
+
+    pub fn set_value(&mut self, bone_index: i32, r#type: TrackType, index: usize, value: f32) -> Result<(), EditError> {
+        self.track_mut(bone_index, r#type)?.set_value(index, value)
+    }
+}
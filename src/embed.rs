@@ -0,0 +1,146 @@
+use std::io::Write;
+
+use crate::animation::{anim_state, Animation, Key, Track, TrackType};
+use crate::bone::{Bone, Joint, Model};
+use crate::hurtbox::{Hurtbox, WorldHurtbox};
+
+// Animation data baked into a binary at compile time. A build script parses the text files as
+// usual and writes them out with `write_static_animation`:
+//
+//     let anim = melee_anim_rs::get_animation_with_hurtboxes(model, hurtboxes, animation)?;
+//     melee_anim_rs::embed::write_static_animation(&mut out, "WAIT", &anim)?;
+//
+// and the crate includes the generated file with `include!(concat!(env!("OUT_DIR"), ...))`.
+// `StaticAnimation::pose_into` then evaluates the keys where they are, in the binary's data;
+// `Animation::from_static` copies them into an `Animation` for the editing and export APIs.
+// examples/assets/animation.rs is generated from the example assets by examples/embed.rs.
+#[derive(Debug)]
+pub struct StaticAnimation {
+    pub frame_count: f32,
+    pub root_bone_index: i32,
+    pub bones: &'static [StaticBone],
+    pub hurtboxes: &'static [Hurtbox],
+}
+
+#[derive(Debug)]
+pub struct StaticBone {
+    pub index: i32,
+    pub parent: i32,
+    pub name: &'static str,
+    pub joint: Joint,
+    pub tracks: &'static [StaticTrack],
+}
+
+#[derive(Debug)]
+pub struct StaticTrack {
+    pub r#type: TrackType,
+    pub keys: &'static [Key],
+}
+
+impl StaticTrack {
+    pub fn get_value(&self, frame: f32) -> f32 {
+        anim_state(self.keys, frame).value(frame)
+    }
+}
+
+impl StaticAnimation {
+    // The skeleton in its rest pose, without tracks.
+    pub fn model(&self) -> Model {
+        let bones = self.bones.iter().map(|bone| {
+            let mut b = Bone::new(bone.index, bone.parent, bone.name.to_string());
+            b.joint = bone.joint;
+            b
+        }).collect::<Vec<_>>();
+        let indexes = Model::make_index(&bones);
+        let mut model = Model {bones, indexes, root_bone_index: self.root_bone_index};
+        model.compute_childs();
+        model
+    }
+
+    // Poses `scratch`, a model from `model`, like `Animation::pose_into`.
+    pub fn pose_into(&self, frame: f32, scratch: &mut Model) {
+        for (bone, pose) in self.bones.iter().zip(scratch.bones.iter_mut()) {
+            for track in bone.tracks {
+                if let Some(component) = pose.joint.component_mut(track.r#type) {
+                    *component = track.get_value(frame);
+                }
+            }
+        }
+        scratch.update_transforms(scratch.root_bone_index, None);
+    }
+
+    // Same as `Animation::query_hurtboxes_into`.
+    pub fn query_hurtboxes_into(&self, frame: f32, scratch: &mut Model, hurtboxes: &mut Vec<WorldHurtbox>) {
+        self.pose_into(frame, scratch);
+        hurtboxes.clear();
+        for hb in self.hurtboxes {
+            if let Some(index) = scratch.indexes.get(&hb.bone_index) {
+                hurtboxes.push(hb.world(&scratch.bones[*index].transform()));
+            }
+        }
+    }
+}
+
+impl Animation {
+    pub fn from_static(data: &StaticAnimation) -> Self {
+        let mut model = data.model();
+        for (bone, b) in data.bones.iter().zip(&mut model.bones) {
+            b.tracks = bone.tracks.iter().map(|track| Track {r#type: track.r#type, keys: track.keys.to_vec()}).collect();
+        }
+        let mut animation = Animation::from_model(model);
+        animation.frame_count = data.frame_count;
+        animation.attach_hurtboxes(data.hurtboxes.to_vec());
+        animation
+    }
+}
+
+// Exact Rust literal for a float, including values `{:?}` cannot spell.
+fn float(value: f32) -> String {
+    if value.is_finite() {
+        format!("{:?}", value)
+    } else {
+        format!("f32::from_bits({:#x})", value.to_bits())
+    }
+}
+
+pub fn write_static_animation<W: Write>(writer: &mut W, name: &str, animation: &Animation) -> std::io::Result<()> {
+    let model = &animation.model;
+    // Parsed values can happen to be close to constants such as PI.
+    writeln!(writer, "#[allow(clippy::approx_constant, clippy::excessive_precision)]")?;
+    writeln!(writer, "pub static {}: ::melee_anim_rs::embed::StaticAnimation = ::melee_anim_rs::embed::StaticAnimation {{", name)?;
+    writeln!(writer, "    frame_count: {},", float(animation.frame_count))?;
+    writeln!(writer, "    root_bone_index: {},", model.root_bone_index)?;
+    writeln!(writer, "    bones: &[")?;
+    for bone in &model.bones {
+        let joint = &bone.joint;
+        writeln!(writer, "        ::melee_anim_rs::embed::StaticBone {{")?;
+        writeln!(writer, "            index: {},", bone.index)?;
+        writeln!(writer, "            parent: {},", bone.parent)?;
+        writeln!(writer, "            name: {:?},", bone.name)?;
+        writeln!(writer, "            joint: ::melee_anim_rs::bone::Joint {{tx: {}, ty: {}, tz: {}, rx: {}, ry: {}, rz: {}}},",
+            float(joint.tx), float(joint.ty), float(joint.tz), float(joint.rx), float(joint.ry), float(joint.rz))?;
+        writeln!(writer, "            tracks: &[")?;
+        for track in &bone.tracks {
+            writeln!(writer, "                ::melee_anim_rs::embed::StaticTrack {{")?;
+            writeln!(writer, "                    r#type: ::melee_anim_rs::animation::TrackType::{:?},", track.r#type)?;
+            writeln!(writer, "                    keys: &[")?;
+            for key in &track.keys {
+                writeln!(writer, "                        ::melee_anim_rs::animation::Key::new({}, {}, {}, ::melee_anim_rs::animation::InterpolationType::{:?}),",
+                    float(key.frame()), float(key.value()), float(key.tan()), key.interpolation_type())?;
+            }
+            writeln!(writer, "                    ],")?;
+            writeln!(writer, "                }},")?;
+        }
+        writeln!(writer, "            ],")?;
+        writeln!(writer, "        }},")?;
+    }
+    writeln!(writer, "    ],")?;
+    writeln!(writer, "    hurtboxes: &[")?;
+    for hurtbox in &animation.hurtboxes {
+        writeln!(writer, "        ::melee_anim_rs::hurtbox::Hurtbox {{bone_index: {}, x1: {}, y1: {}, z1: {}, x2: {}, y2: {}, z2: {}, size: {}, r#type: ::melee_anim_rs::hurtbox::HurtboxType::{:?}, grabable: {}}},",
+            hurtbox.bone_index, float(hurtbox.x1), float(hurtbox.y1), float(hurtbox.z1), float(hurtbox.x2), float(hurtbox.y2), float(hurtbox.z2),
+            float(hurtbox.size), hurtbox.r#type, hurtbox.grabable)?;
+    }
+    writeln!(writer, "    ],")?;
+    writeln!(writer, "}};")
+}
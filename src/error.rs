@@ -0,0 +1,179 @@
+use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::FromStr;
+
+use crate::dat::{EncodeFigaTreeError, PatchAnimationError};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Input {
+    Model,
+    Hurtbox,
+    Animation,
+}
+
+#[derive(Debug)]
+pub enum Cause {
+    Int(ParseIntError),
+    Float(ParseFloatError),
+    UnknownInterpolationType,
+    UnknownTrackType,
+    UnknownHurtboxType,
+    FieldCount { expected: usize, found: usize },
+    UnexpectedLine,
+    DuplicateBone,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io { input: Input, source: std::io::Error },
+    Parse { input: Input, line: Option<usize>, column: Option<usize>, token: String, cause: Cause },
+    Multiple { input: Input, errors: Vec<Error> },
+    Encode(EncodeFigaTreeError),
+    Patch(PatchAnimationError),
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum ParseMode {
+    // Skips lines that are not understood
+    #[default]
+    Lenient,
+    // Reports every line that is not understood
+    Strict,
+}
+
+
+impl Error {
+    pub fn io(input: Input, source: std::io::Error) -> Self {
+        Error::Io {input, source}
+    }
+
+    pub fn parse(input: Input, token: &str, cause: Cause) -> Self {
+        Error::Parse {input, line: None, column: None, token: token.to_string(), cause}
+    }
+
+    // Sets the 1-based column of the offending token, unless one was already recorded.
+    pub fn at_column(mut self, number: usize) -> Self {
+        if let Error::Parse {column, ..} = &mut self {
+            if column.is_none() {
+                *column = Some(number);
+            }
+        }
+        self
+    }
+
+    // Sets the 1-based line number, unless a more precise one was already recorded.
+    pub fn at_line(mut self, number: usize) -> Self {
+        if let Error::Parse {line, ..} = &mut self {
+            if line.is_none() {
+                *line = Some(number);
+            }
+        }
+        self
+    }
+
+    pub fn input(&self) -> Input {
+        match self {
+            Error::Io {input, ..} | Error::Parse {input, ..} | Error::Multiple {input, ..} => *input,
+            Error::Encode(_) | Error::Patch(_) => Input::Animation,
+        }
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Error::Parse {line, ..} => *line,
+            _ => None,
+        }
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        match self {
+            Error::Parse {column, ..} => *column,
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Input::Model => f.write_str("model"),
+            Input::Hurtbox => f.write_str("hurtbox"),
+            Input::Animation => f.write_str("animation"),
+        }
+    }
+}
+
+impl fmt::Display for Cause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cause::Int(e) => write!(f, "invalid integer: {}", e),
+            Cause::Float(e) => write!(f, "invalid float: {}", e),
+            Cause::UnknownInterpolationType => f.write_str("unknown interpolation type"),
+            Cause::UnknownTrackType => f.write_str("unknown track type"),
+            Cause::UnknownHurtboxType => f.write_str("unknown hurtbox type"),
+            Cause::FieldCount {expected, found} => write!(f, "expected {} fields, found {}", expected, found),
+            Cause::UnexpectedLine => f.write_str("unexpected line"),
+            Cause::DuplicateBone => f.write_str("bone index already used"),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io {input, source} => write!(f, "failed to read {} data: {}", input, source),
+            Error::Parse {input, line, column, token, cause} => {
+                write!(f, "{} data", input)?;
+                if let Some(line) = line {
+                    write!(f, ", line {}", line)?;
+                }
+                if let Some(column) = column {
+                    write!(f, ", column {}", column)?;
+                }
+                write!(f, ": `{}`: {}", token, cause)
+            },
+            Error::Multiple {input, errors} => {
+                write!(f, "{} errors in {} data", errors.len(), input)?;
+                for error in errors {
+                    write!(f, "\n  {}", error)?;
+                }
+                Ok(())
+            },
+            Error::Encode(e) => write!(f, "failed to encode animation: {}", e),
+            Error::Patch(e) => write!(f, "failed to patch animation data: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io {source, ..} => Some(source),
+            Error::Parse {cause: Cause::Int(e), ..} => Some(e),
+            Error::Parse {cause: Cause::Float(e), ..} => Some(e),
+            Error::Encode(e) => Some(e),
+            Error::Patch(e) => Some(e),
+            Error::Parse {..} | Error::Multiple {..} => None,
+        }
+    }
+}
+
+impl From<EncodeFigaTreeError> for Error {
+    fn from(e: EncodeFigaTreeError) -> Self {
+        Error::Encode(e)
+    }
+}
+
+impl From<PatchAnimationError> for Error {
+    fn from(e: PatchAnimationError) -> Self {
+        Error::Patch(e)
+    }
+}
+
+pub(crate) fn parse_i32(input: Input, token: &str) -> Result<i32, Error> {
+    i32::from_str(token).map_err(|e| Error::parse(input, token, Cause::Int(e)))
+}
+
+pub(crate) fn parse_f32(input: Input, token: &str) -> Result<f32, Error> {
+    f32::from_str(token).map_err(|e| Error::parse(input, token, Cause::Float(e)))
+}
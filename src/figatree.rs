@@ -0,0 +1,187 @@
+//! Tokenizer for the text figatree format dumped by HSDLib.
+//!
+//! ```text
+//! file          = { line } ;
+//! line          = { statement } [ comment ] ;
+//! statement     = frame-count | node | track | "{" | "}" | key ;
+//! frame-count   = "FrameCount" ":" number ;
+//! node          = "Node" integer ":" ;
+//! track         = "HSD_A_J_" name ;                        e.g. HSD_A_J_ROTX
+//! key           = number number number interpolation ;    frame, value, tangent
+//! interpolation = "HSD_A_OP_" name ;                       e.g. HSD_A_OP_SPL
+//! comment       = ( "//" | "#" ) { any character } ;
+//! ```
+//!
+//! Tokens are separated by any run of spaces, tabs or carriage returns. `{`, `}` and `:`
+//! are tokens on their own and need no separator. Numbers accept everything `f32::from_str`
+//! does, including exponent notation. Errors carry the 1-based column of the bad token.
+
+use std::fmt;
+
+use crate::animation::{Key, Track, TrackType};
+use crate::error::{parse_f32, parse_i32, Cause, Error, Input};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub column: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Statement {
+    FrameCount(f32),
+    Node(i32),
+    Track(TrackType),
+    Open,
+    Close,
+    Key(Key),
+}
+
+// Problems found while loading a figatree that do not prevent building the animation.
+// Lines are 1-based.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Diagnostic {
+    NodeCountMismatch { nodes: usize, bones: usize },
+    UnknownNode { line: usize, node: i32 },
+    DuplicateTrack { line: usize, node: i32, track: TrackType },
+    KeyOutOfOrder { line: usize, node: i32, track: TrackType, frame: f32, previous: f32 },
+    KeyClamped { line: usize, node: i32, track: TrackType, frame: f32, frame_count: f32 },
+    StraySlope { line: usize, node: i32, track: TrackType },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Diagnostic::NodeCountMismatch {nodes, bones} => write!(f, "animation has {} nodes but the model has {} bones", nodes, bones),
+            Diagnostic::UnknownNode {line, node} => write!(f, "line {}: node {} is not a bone of the model", line, node),
+            Diagnostic::DuplicateTrack {line, node, track} => write!(f, "line {}: node {} already has a {} track", line, node, track),
+            Diagnostic::KeyOutOfOrder {line, node, track, frame, previous} => write!(f, "line {}: node {} {} key at frame {} comes after frame {}", line, node, track, frame, previous),
+            Diagnostic::KeyClamped {line, node, track, frame, frame_count} => write!(f, "line {}: node {} {} key at frame {} clamped to frame count {}", line, node, track, frame, frame_count),
+            Diagnostic::StraySlope {line, node, track} => write!(f, "line {}: node {} {} slope key does not follow a key", line, node, track),
+        }
+    }
+}
+
+pub struct Tokens<'a> {
+    rest: &'a str,
+    column: usize,
+}
+
+pub struct Statements<'a> {
+    tokens: Tokens<'a>,
+    failed: bool,
+}
+
+fn is_punctuation(c: char) -> bool {
+    c == '{' || c == '}' || c == ':'
+}
+
+fn is_comment(s: &str) -> bool {
+    s.starts_with('#') || s.starts_with("//")
+}
+
+pub fn tokens(line: &str) -> Tokens<'_> {
+    Tokens {rest: line, column: 1}
+}
+
+pub fn statements(line: &str) -> Statements<'_> {
+    Statements {tokens: tokens(line), failed: false}
+}
+
+impl<'a> Tokens<'a> {
+    fn advance(&mut self, bytes: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(bytes);
+        self.column += taken.chars().count();
+        self.rest = rest;
+        taken
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let whitespace = self.rest.len() - self.rest.trim_start().len();
+        self.advance(whitespace);
+        if self.rest.is_empty() || is_comment(self.rest) {
+            self.rest = "";
+            return None;
+        }
+        let column = self.column;
+        let first = self.rest.chars().next()?;
+        if is_punctuation(first) {
+            let text = self.advance(first.len_utf8());
+            return Some(Token {text, column});
+        }
+        let end = self.rest.char_indices()
+            .find(|(i, c)| c.is_whitespace() || is_punctuation(*c) || is_comment(&self.rest[*i..]))
+            .map_or(self.rest.len(), |(i, _)| i);
+        let text = self.advance(end);
+        Some(Token {text, column})
+    }
+}
+
+fn expect<'a>(tokens: &mut Tokens<'a>, after: &Token<'a>, expected: usize, found: usize) -> Result<Token<'a>, Error> {
+    tokens.next().ok_or_else(|| Error::parse(Input::Animation, after.text, Cause::FieldCount {expected, found}).at_column(after.column))
+}
+
+fn expect_colon<'a>(tokens: &mut Tokens<'a>, after: &Token<'a>) -> Result<(), Error> {
+    let colon = expect(tokens, after, 3, 2)?;
+    if colon.text != ":" {
+        return Err(Error::parse(Input::Animation, colon.text, Cause::UnexpectedLine).at_column(colon.column));
+    }
+    Ok(())
+}
+
+fn parse_key<'a>(first: Token<'a>, tokens: &mut Tokens<'a>) -> Result<Key, Error> {
+    let frame = parse_f32(Input::Animation, first.text).map_err(|e| e.at_column(first.column))?;
+    let value_token = expect(tokens, &first, 4, 1)?;
+    let value = parse_f32(Input::Animation, value_token.text).map_err(|e| e.at_column(value_token.column))?;
+    let tan_token = expect(tokens, &value_token, 4, 2)?;
+    let tan = parse_f32(Input::Animation, tan_token.text).map_err(|e| e.at_column(tan_token.column))?;
+    let type_token = expect(tokens, &tan_token, 4, 3)?;
+    let interpolation_type = Key::parse_interpolation_type(type_token.text).map_err(|e| e.at_column(type_token.column))?;
+    Ok(Key::new(frame, value, tan, interpolation_type))
+}
+
+impl<'a> Statements<'a> {
+    fn parse(&mut self, token: Token<'a>) -> Result<Statement, Error> {
+        let tokens = &mut self.tokens;
+        match token.text {
+            "{" => Ok(Statement::Open),
+            "}" => Ok(Statement::Close),
+            "FrameCount" => {
+                expect_colon(tokens, &token)?;
+                let count = expect(tokens, &token, 3, 2)?;
+                parse_f32(Input::Animation, count.text).map(Statement::FrameCount).map_err(|e| e.at_column(count.column))
+            },
+            "Node" => {
+                let index = expect(tokens, &token, 3, 1)?;
+                let node = parse_i32(Input::Animation, index.text).map_err(|e| e.at_column(index.column))?;
+                expect_colon(tokens, &index)?;
+                Ok(Statement::Node(node))
+            },
+            text if text.starts_with("HSD_A_J_") => {
+                Track::parse_type(text).map(Statement::Track).map_err(|e| e.at_column(token.column))
+            },
+            text if text.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+' || c == '.') => {
+                parse_key(token, tokens).map(Statement::Key)
+            },
+            text => Err(Error::parse(Input::Animation, text, Cause::UnexpectedLine).at_column(token.column)),
+        }
+    }
+}
+
+impl<'a> Iterator for Statements<'a> {
+    type Item = Result<Statement, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let token = self.tokens.next()?;
+        let statement = self.parse(token);
+        self.failed = statement.is_err();
+        Some(statement)
+    }
+}
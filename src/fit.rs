@@ -0,0 +1,296 @@
+use std::fmt;
+
+use crate::animation::{InterpolationType, Key, Track, TrackType};
+
+// Fits keys to one value per frame, so that `Track::get_value` stays within `tolerance` of every
+// sample. Two fits are made and the one with fewer keys is kept:
+// - segments grown greedily from each key, keeping the longest of a constant, linear or spline
+//   segment, which suits held poses, straight runs and corners
+// - spline keys inserted where the error is largest and then removed where they are not needed,
+//   with every tangent solved at once, which suits smooth curves
+// Rounding can keep both fits out of tolerance, mostly when it is zero; the keys are then a
+// linear key on every frame, which is exact on every sample.
+//
+// Past its last key a track no longer interpolates, so the keys end one frame after the last
+// sample, at `values.len()`: the frame count of an animation made of these samples.
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FitError {
+    // Tolerances must be zero or more
+    InvalidTolerance(f32),
+    NonFiniteSample { frame: usize, value: f32 },
+}
+
+impl fmt::Display for FitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FitError::InvalidTolerance(tolerance) => write!(f, "invalid fitting tolerance {}", tolerance),
+            FitError::NonFiniteSample {frame, value} => write!(f, "sample {} at frame {} is not finite", value, frame),
+        }
+    }
+}
+
+impl std::error::Error for FitError {}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Kind {
+    Constant,
+    Linear,
+    Spline,
+}
+
+fn tangents(values: &[f32]) -> Vec<f32> {
+    let n = values.len();
+    (0..n).map(|i| {
+        if n < 2 {
+            0.
+        } else if i == 0 {
+            values[1] - values[0]
+        } else if i == n - 1 {
+            values[n - 1] - values[n - 2]
+        } else {
+            (values[i + 1] - values[i - 1]) / 2.
+        }
+    }).collect()
+}
+
+fn interpolation_type(kind: Kind) -> InterpolationType {
+    match kind {
+        Kind::Constant => InterpolationType::HSD_A_OP_CON,
+        Kind::Linear => InterpolationType::HSD_A_OP_LIN,
+        Kind::Spline => InterpolationType::HSD_A_OP_SPL,
+    }
+}
+
+// Largest error over `values` and the frame where it is reached. Large samples can overflow a
+// spline, whose NaN values count as an infinite error.
+fn max_error(track: &Track, values: &[f32]) -> (f32, usize) {
+    let samples = track.sample(0., (values.len() - 1) as f32, 1.);
+    samples.iter().zip(values).enumerate().fold((0., 0), |max, (frame, ((_, value), sample))| {
+        let error = (value - sample).abs();
+        let error = if error.is_nan() { f32::INFINITY } else { error };
+        if error > max.0 { (error, frame) } else { max }
+    })
+}
+
+fn within(track: &Track, values: &[f32], frames: std::ops::RangeInclusive<usize>, tolerance: f32) -> bool {
+    frames.into_iter().all(|frame| (track.get_value(frame as f32) - values[frame]).abs() <= tolerance)
+}
+
+fn segment_track(kind: Kind, values: &[f32], start: usize, start_tan: f32, end: usize, end_tan: f32) -> Track {
+    let end_type = if kind == Kind::Spline { InterpolationType::HSD_A_OP_SPL } else { InterpolationType::HSD_A_OP_CON };
+    Track {
+        r#type: TrackType::HSD_A_J_NONE,
+        keys: vec![
+            Key::new(start as f32, values[start], start_tan, interpolation_type(kind)),
+            Key::new(end as f32, values[end], end_tan, end_type),
+            Key::new(end as f32 + 1., values[end], 0., InterpolationType::HSD_A_OP_CON),
+        ],
+    }
+}
+
+// Tangents of a segment of `kind` from `start` to `end` that stays within tolerance, if any.
+// A spline's value is linear in its tangents, so they are also solved by least squares: the end
+// tangent alone, or both when the start tangent is `free` and not shared with a previous spline.
+fn fit(kind: Kind, values: &[f32], tans: &[f32], start: usize, end: usize, free: bool, tolerance: f32) -> Option<(f32, f32)> {
+    let fits = |(start_tan, end_tan)| {
+        within(&segment_track(kind, values, start, start_tan, end, end_tan), values, start..=end, tolerance)
+    };
+    if fits((tans[start], tans[end])) {
+        return Some((tans[start], tans[end]));
+    }
+    if kind != Kind::Spline {
+        return None;
+    }
+    let value = |start_tan, end_tan, frame: usize| segment_track(kind, values, start, start_tan, end, end_tan).get_value(frame as f32);
+    let (mut a00, mut a01, mut a11, mut b0, mut b1) = (0., 0., 0., 0., 0.);
+    for (frame, sample) in values.iter().enumerate().take(end).skip(start + 1) {
+        let base = value(0., 0., frame);
+        let w0 = value(1., 0., frame) - base;
+        let w1 = value(0., 1., frame) - base;
+        let r = sample - base;
+        a00 += w0 * w0;
+        a01 += w0 * w1;
+        a11 += w1 * w1;
+        b0 += w0 * r;
+        b1 += w1 * r;
+    }
+    let det = a00 * a11 - a01 * a01;
+    let mut candidates = vec![];
+    if free && det.abs() > f32::EPSILON {
+        candidates.push(((b0 * a11 - b1 * a01) / det, (a00 * b1 - a01 * b0) / det));
+    }
+    if a11 > 0. {
+        candidates.push((tans[start], (b1 - a01 * tans[start]) / a11));
+    }
+    candidates.into_iter().find(|tangents| fits(*tangents))
+}
+
+// Longest segment of `kind` starting at `start`, stopping at the first end that does not fit.
+fn longest(kind: Kind, values: &[f32], tans: &[f32], start: usize, free: bool, tolerance: f32) -> (usize, (f32, f32)) {
+    let mut best = (start + 1, (tans[start], tans[start + 1]));
+    while best.0 + 1 < values.len() {
+        match fit(kind, values, tans, start, best.0 + 1, free, tolerance) {
+            Some(tangents) => best = (best.0 + 1, tangents),
+            None => break,
+        }
+    }
+    best
+}
+
+fn greedy_keys(values: &[f32], tolerance: f32) -> Option<Vec<Key>> {
+    let n = values.len();
+    let mut tans = tangents(values);
+
+    let mut segments: Vec<(usize, Kind)> = vec![];
+    let mut start = 0;
+    while start + 1 < n {
+        // The start tangent is only shared with a spline segment ending here
+        let free = segments.last().map(|s| s.1) != Some(Kind::Spline);
+        // Any segment is exact over a single frame, simpler kinds win ties
+        let mut best = (longest(Kind::Constant, values, &tans, start, free, tolerance), Kind::Constant);
+        for kind in [Kind::Linear, Kind::Spline] {
+            let end = longest(kind, values, &tans, start, free, tolerance);
+            if end.0 > best.0.0 {
+                best = (end, kind);
+            }
+        }
+        segments.push((start, best.1));
+        let (end, (start_tan, end_tan)) = best.0;
+        if best.1 == Kind::Spline {
+            tans[start] = start_tan;
+            tans[end] = end_tan;
+        }
+        start = end;
+    }
+
+    let mut keys = vec![];
+    for (i, (start, kind)) in segments.iter().enumerate() {
+        // A spline segment followed by a linear or constant key takes its end slope from an SLP key
+        if i > 0 && segments[i - 1].1 == Kind::Spline && *kind != Kind::Spline {
+            keys.push(Key::new(*start as f32, 0., tans[*start], InterpolationType::HSD_A_OP_SLP));
+        }
+        keys.push(Key::new(*start as f32, values[*start], tans[*start], interpolation_type(*kind)));
+    }
+    // The last segment ends on a key at the last sample: a spline key carries its end slope, and
+    // a constant segment close enough to the last sample is held up to the terminal key instead
+    match segments.last() {
+        Some((_, Kind::Spline)) => keys.push(Key::new((n - 1) as f32, values[n - 1], tans[n - 1], InterpolationType::HSD_A_OP_SPL)),
+        Some((start, Kind::Constant)) if (values[n - 1] - values[*start]).abs() <= tolerance => (),
+        _ => keys.push(Key::new((n - 1) as f32, values[n - 1], 0., InterpolationType::HSD_A_OP_CON)),
+    }
+    keys.push(Key::new(n as f32, values[n - 1], 0., InterpolationType::HSD_A_OP_CON));
+
+    let track = Track {r#type: TrackType::HSD_A_J_NONE, keys};
+    if max_error(&track, values).0 <= tolerance { Some(track.keys) } else { None }
+}
+
+// Spline keys at `knots` with every tangent solved by least squares. A sample only depends on
+// the tangents of the two keys around it, so the normal equations are tridiagonal.
+fn spline_keys(values: &[f32], tans: &[f32], knots: &[usize]) -> Vec<Key> {
+    let k = knots.len();
+    // Pulls tangents that no sample depends on towards the finite differences
+    let damping = 1e-6;
+    let mut lower = vec![0.; k];
+    let mut diagonal = vec![damping; k];
+    let mut upper = vec![0.; k];
+    let mut rhs = knots.iter().map(|knot| damping * tans[*knot]).collect::<Vec<_>>();
+    for i in 0..k - 1 {
+        let (start, end) = (knots[i], knots[i + 1]);
+        let base = segment_track(Kind::Spline, values, start, 0., end, 0.);
+        let start_unit = segment_track(Kind::Spline, values, start, 1., end, 0.);
+        let end_unit = segment_track(Kind::Spline, values, start, 0., end, 1.);
+        for (frame, sample) in values.iter().enumerate().take(end).skip(start + 1) {
+            let value = base.get_value(frame as f32);
+            let w0 = start_unit.get_value(frame as f32) - value;
+            let w1 = end_unit.get_value(frame as f32) - value;
+            let r = sample - value;
+            diagonal[i] += w0 * w0;
+            diagonal[i + 1] += w1 * w1;
+            upper[i] += w0 * w1;
+            lower[i + 1] += w0 * w1;
+            rhs[i] += w0 * r;
+            rhs[i + 1] += w1 * r;
+        }
+    }
+    for i in 1..k {
+        let m = lower[i] / diagonal[i - 1];
+        diagonal[i] -= m * upper[i - 1];
+        rhs[i] -= m * rhs[i - 1];
+    }
+    let mut solved = vec![0.; k];
+    for i in (0..k).rev() {
+        let next = if i + 1 < k { upper[i] * solved[i + 1] } else { 0. };
+        solved[i] = (rhs[i] - next) / diagonal[i];
+    }
+    knots.iter().zip(solved)
+        .map(|(knot, tan)| Key::new(*knot as f32, values[*knot], tan, InterpolationType::HSD_A_OP_SPL))
+        .collect()
+}
+
+fn refined_keys(values: &[f32], tolerance: f32) -> Vec<Key> {
+    let n = values.len();
+    // The terminal key is a spline key too, one frame past the last sample on its tangent
+    let mut tans = tangents(values);
+    tans.push(tans[n - 1]);
+    let mut extended = values.to_vec();
+    extended.push(values[n - 1] + tans[n - 1]);
+    let error = |knots: &[usize]| max_error(&Track {r#type: TrackType::HSD_A_J_NONE, keys: spline_keys(&extended, &tans, knots)}, values);
+
+    // Keys are exact at their frame up to rounding, so insertions stop once the worst frame is
+    // already a key
+    let mut knots = vec![0, n];
+    loop {
+        let (max, frame) = error(&knots);
+        if max <= tolerance || knots.binary_search(&frame).is_ok() {
+            break;
+        }
+        let index = knots.partition_point(|knot| *knot < frame);
+        knots.insert(index, frame);
+    }
+    let mut i = 1;
+    while i + 1 < knots.len() {
+        let mut fewer = knots.clone();
+        fewer.remove(i);
+        if error(&fewer).0 <= tolerance {
+            knots = fewer;
+        } else {
+            i += 1;
+        }
+    }
+    spline_keys(&extended, &tans, &knots)
+}
+
+fn every_frame_keys(values: &[f32]) -> Vec<Key> {
+    let n = values.len();
+    values.iter().enumerate()
+        .map(|(frame, value)| Key::new(frame as f32, *value, 0., InterpolationType::HSD_A_OP_LIN))
+        .chain([Key::new(n as f32, values[n - 1], 0., InterpolationType::HSD_A_OP_CON)])
+        .collect()
+}
+
+pub fn fit_keys(values: &[f32], tolerance: f32) -> Result<Vec<Key>, FitError> {
+    if tolerance.is_nan() || tolerance < 0. {
+        return Err(FitError::InvalidTolerance(tolerance));
+    }
+    if let Some((frame, value)) = values.iter().enumerate().find(|(_, value)| !value.is_finite()) {
+        return Err(FitError::NonFiniteSample {frame, value: *value});
+    }
+    if values.is_empty() {
+        return Ok(vec![]);
+    }
+    let refined = Some(refined_keys(values, tolerance)).filter(|keys| {
+        max_error(&Track {r#type: TrackType::HSD_A_J_NONE, keys: keys.clone()}, values).0 <= tolerance
+    });
+    Ok(match (greedy_keys(values, tolerance), refined) {
+        (Some(greedy), Some(refined)) => if greedy.len() < refined.len() { greedy } else { refined },
+        (Some(keys), None) | (None, Some(keys)) => keys,
+        (None, None) => every_frame_keys(values),
+    })
+}
+
+impl Track {
+    pub fn from_samples(r#type: TrackType, values: &[f32], tolerance: f32) -> Result<Self, FitError> {
+        Ok(Track {r#type, keys: fit_keys(values, tolerance)?})
+    }
+}
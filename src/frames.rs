@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use nalgebra::geometry::Isometry3;
+
+use crate::animation::{Animation, Point2, TrackCursor};
+use crate::bone::{Joint, Model};
+
+// Walks the frames of an animation in order, reusing one posed model, one hurtbox buffer
+// and a cursor per track. Not an `Iterator` because each frame borrows from the walker:
+//
+//     let mut frames = animation.frames();
+//     while let Some(frame) = frames.next() { ... }
+pub struct Frames<'a> {
+    animation: &'a Animation,
+    model: Model,
+    cursors: Vec<Vec<TrackCursor>>,
+    hurtboxes_2d: Vec<(Point2<f32>, Point2<f32>, f32)>,
+    frame: usize,
+    frame_count: usize,
+}
+
+pub struct Frame<'f> {
+    pub frame: usize,
+    pub model: &'f Model,
+    pub hurtboxes_2d: &'f [(Point2<f32>, Point2<f32>, f32)],
+}
+
+// Joints and world transforms of every bone at every integer frame, stored frame-major.
+#[derive(Debug, Clone)]
+pub struct BakedAnimation {
+    pub frame_count: usize,
+    pub indexes: BTreeMap<i32, usize>,
+    pub joints: Vec<Joint>,
+    pub transforms: Vec<Isometry3<f32>>,
+}
+
+pub(crate) fn frame_count(animation: &Animation) -> usize {
+    (animation.frame_count.ceil() as usize).max(1)
+}
+
+pub(crate) fn update_joints(model: &mut Model, cursors: &mut [Vec<TrackCursor>], frame: f32) {
+    for (bone, cursors) in model.bones.iter_mut().zip(cursors) {
+        for (track, cursor) in bone.tracks.iter().zip(cursors) {
+            let value = track.get_value_with_cursor(frame, cursor);
+            if let Some(component) = bone.joint.component_mut(track.r#type) {
+                *component = value;
+            }
+        }
+    }
+}
+
+impl<'a> Frames<'a> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Frame<'_>> {
+        if self.frame >= self.frame_count {
+            return None;
+        }
+        let frame = self.frame;
+        self.frame += 1;
+        update_joints(&mut self.model, &mut self.cursors, frame as f32);
+        self.model.update_transforms(self.model.root_bone_index, None);
+        self.hurtboxes_2d.clear();
+        self.animation.hurtboxes_2d(&self.model, &mut self.hurtboxes_2d);
+        Some(Frame {frame, model: &self.model, hurtboxes_2d: &self.hurtboxes_2d})
+    }
+
+    pub fn len(&self) -> usize {
+        self.frame_count - self.frame
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Animation {
+    pub fn frames(&self) -> Frames<'_> {
+        let model = self.model.clone();
+        let cursors = model.bones.iter().map(|bone| bone.tracks.iter().map(|track| track.cursor()).collect()).collect();
+        Frames {animation: self, model, cursors, hurtboxes_2d: vec![], frame: 0, frame_count: frame_count(self)}
+    }
+
+    pub fn bake(&self) -> BakedAnimation {
+        let frame_count = frame_count(self);
+        let bone_count = self.model.bones.len();
+        let mut joints = Vec::with_capacity(frame_count * bone_count);
+        let mut transforms = Vec::with_capacity(frame_count * bone_count);
+        let mut frames = self.frames();
+        while let Some(frame) = frames.next() {
+            joints.extend(frame.model.bones.iter().map(|bone| bone.joint));
+            transforms.extend(frame.model.bones.iter().map(|bone| bone.transform()));
+        }
+        BakedAnimation {frame_count, indexes: self.model.indexes.clone(), joints, transforms}
+    }
+}
+
+impl BakedAnimation {
+    pub fn bone_count(&self) -> usize {
+        self.indexes.len()
+    }
+
+    pub fn pose(&self, frame: usize) -> &[Isometry3<f32>] {
+        let bone_count = self.bone_count();
+        &self.transforms[frame * bone_count..(frame + 1) * bone_count]
+    }
+
+    pub fn joint(&self, frame: usize, bone_index: i32) -> Option<&Joint> {
+        let index = self.indexes.get(&bone_index)?;
+        self.joints.get(frame * self.bone_count() + index)
+    }
+
+    pub fn transform(&self, frame: usize, bone_index: i32) -> Option<&Isometry3<f32>> {
+        let index = self.indexes.get(&bone_index)?;
+        self.transforms.get(frame * self.bone_count() + index)
+    }
+
+    // World transform between integer frames, interpolated linearly and clamped to the table.
+    // Integer frames, including the clamped ends, return the stored transform as is.
+    pub fn sample(&self, frame: f32, bone_index: i32) -> Option<Isometry3<f32>> {
+        let last = self.frame_count.checked_sub(1)?;
+        let frame = frame.max(0.).min(last as f32);
+        let before = frame.floor() as usize;
+        let t0 = self.transform(before, bone_index)?;
+        let weight = frame - before as f32;
+        if weight == 0. {
+            return Some(*t0);
+        }
+        let t1 = self.transform(before + 1, bone_index)?;
+        Some(t0.lerp_slerp(t1, weight))
+    }
+}
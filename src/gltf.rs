@@ -0,0 +1,355 @@
+use std::io::{Error, ErrorKind, Write};
+use std::f32::consts::PI;
+use nalgebra::{Point3, Vector3};
+
+use crate::animation::{Animation, InterpolationType, Track, TrackType};
+use crate::bone::Model;
+use crate::hurtbox::Hurtbox;
+
+const FPS: f32 = 60.;
+const CAPSULE_SEGMENTS: usize = 16;
+const CAPSULE_RINGS: usize = 4;
+
+const FLOAT: u32 = 5126;
+const UNSIGNED_SHORT: u32 = 5123;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+struct Builder {
+    bin: Vec<u8>,
+    buffer_views: Vec<String>,
+    accessors: Vec<String>,
+}
+
+impl Builder {
+    fn new() -> Self {
+        Builder {bin: vec![], buffer_views: vec![], accessors: vec![]}
+    }
+
+    fn push_view(&mut self, bytes: &[u8], target: Option<u32>) -> usize {
+        pad(&mut self.bin, 0);
+        let offset = self.bin.len();
+        self.bin.extend_from_slice(bytes);
+        let target = match target {
+            Some(target) => format!(",\"target\":{}", target),
+            None => String::new(),
+        };
+        self.buffer_views.push(format!("{{\"buffer\":0,\"byteOffset\":{},\"byteLength\":{}{}}}", offset, bytes.len(), target));
+        self.buffer_views.len() - 1
+    }
+
+    fn push_floats(&mut self, data: &[f32], width: usize, target: Option<u32>, bounds: bool) -> std::io::Result<usize> {
+        finite(data)?;
+        let bytes = data.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect::<Vec<_>>();
+        let view = self.push_view(&bytes, target);
+        let kind = match width {
+            1 => "SCALAR",
+            3 => "VEC3",
+            _ => "VEC4",
+        };
+        let bounds = if bounds {
+            let mut min = vec![f32::INFINITY; width];
+            let mut max = vec![f32::NEG_INFINITY; width];
+            for chunk in data.chunks(width) {
+                for (i, v) in chunk.iter().enumerate() {
+                    min[i] = min[i].min(*v);
+                    max[i] = max[i].max(*v);
+                }
+            }
+            format!(",\"min\":{},\"max\":{}", json_floats(&min)?, json_floats(&max)?)
+        } else {
+            String::new()
+        };
+        self.accessors.push(format!("{{\"bufferView\":{},\"componentType\":{},\"count\":{},\"type\":\"{}\"{}}}", view, FLOAT, data.len() / width, kind, bounds));
+        Ok(self.accessors.len() - 1)
+    }
+
+    fn push_indices(&mut self, data: &[u16]) -> usize {
+        let bytes = data.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect::<Vec<_>>();
+        let view = self.push_view(&bytes, Some(ELEMENT_ARRAY_BUFFER));
+        self.accessors.push(format!("{{\"bufferView\":{},\"componentType\":{},\"count\":{},\"type\":\"SCALAR\"}}", view, UNSIGNED_SHORT, data.len()));
+        self.accessors.len() - 1
+    }
+}
+
+fn pad(bytes: &mut Vec<u8>, fill: u8) {
+    let len = bytes.len().div_ceil(4) * 4;
+    bytes.resize(len, fill);
+}
+
+// JSON has no NaN or infinity, and glTF readers expect finite data.
+fn finite(values: &[f32]) -> std::io::Result<()> {
+    match values.iter().find(|v| !v.is_finite()) {
+        Some(v) => Err(Error::new(ErrorKind::InvalidData, format!("cannot write non-finite value {} to glTF", v))),
+        None => Ok(()),
+    }
+}
+
+fn json_floats(values: &[f32]) -> std::io::Result<String> {
+    finite(values)?;
+    let values = values.iter().map(|v| format!("{:?}", v)).collect::<Vec<_>>();
+    Ok(format!("[{}]", values.join(",")))
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn capsule_mesh(hurtbox: &Hurtbox) -> (Vec<f32>, Vec<f32>, Vec<u16>) {
+    let p1 = Point3::new(hurtbox.x1, hurtbox.y1, hurtbox.z1);
+    let p2 = Point3::new(hurtbox.x2, hurtbox.y2, hurtbox.z2);
+    let axis = if hurtbox.norm() > 0. { (p2 - p1).normalize() } else { Vector3::y() };
+    let helper = if axis.x.abs() < 0.9 { Vector3::x() } else { Vector3::z() };
+    let u = axis.cross(&helper).normalize();
+    let v = axis.cross(&u);
+
+    let (mut positions, mut normals, mut indices) = (vec![], vec![], vec![]);
+    let rows = 2 * (CAPSULE_RINGS + 1);
+    for row in 0..rows {
+        let (center, phi) = if row <= CAPSULE_RINGS {
+            (p1, -PI / 2. + PI / 2. * row as f32 / CAPSULE_RINGS as f32)
+        } else {
+            (p2, PI / 2. * (row - CAPSULE_RINGS - 1) as f32 / CAPSULE_RINGS as f32)
+        };
+        for segment in 0..=CAPSULE_SEGMENTS {
+            let theta = 2. * PI * segment as f32 / CAPSULE_SEGMENTS as f32;
+            let normal = (u * theta.cos() + v * theta.sin()) * phi.cos() + axis * phi.sin();
+            let position = center + normal * hurtbox.size;
+            positions.extend_from_slice(&[position.x, position.y, position.z]);
+            normals.extend_from_slice(&[normal.x, normal.y, normal.z]);
+        }
+    }
+    let stride = (CAPSULE_SEGMENTS + 1) as u16;
+    for row in 0..(rows - 1) as u16 {
+        for segment in 0..CAPSULE_SEGMENTS as u16 {
+            let a = row * stride + segment;
+            let b = a + 1;
+            let c = b + stride;
+            let d = a + stride;
+            indices.extend_from_slice(&[a, b, c, a, c, d]);
+        }
+    }
+    (positions, normals, indices)
+}
+
+fn is_translation(r#type: TrackType) -> bool {
+    matches!(r#type, TrackType::HSD_A_J_TRAX | TrackType::HSD_A_J_TRAY | TrackType::HSD_A_J_TRAZ)
+}
+
+fn is_rotation(r#type: TrackType) -> bool {
+    matches!(r#type, TrackType::HSD_A_J_ROTX | TrackType::HSD_A_J_ROTY | TrackType::HSD_A_J_ROTZ)
+}
+
+// (frame, value, in tangent, out tangent)
+type SplineKey = (f32, f32, f32, f32);
+
+// Returns every key if the track only uses HSD_A_OP_SPL keys, with optional
+// HSD_A_OP_SLP keys overriding the outgoing tangent.
+fn spline_keys(track: &Track) -> Option<Vec<SplineKey>> {
+    let mut keys: Vec<SplineKey> = vec![];
+    for key in &track.keys {
+        match key.interpolation_type() {
+            InterpolationType::HSD_A_OP_SPL => {
+                if let Some(last) = keys.last() {
+                    if key.frame() <= last.0 {
+                        return None;
+                    }
+                }
+                keys.push((key.frame(), key.value(), key.tan(), key.tan()));
+            },
+            InterpolationType::HSD_A_OP_SLP => keys.last_mut()?.3 = key.tan(),
+            _ => return None,
+        }
+    }
+    match keys.first() {
+        Some(first) if first.0 == 0. && keys.len() > 1 => Some(keys),
+        _ => None,
+    }
+}
+
+// Cubic spline translation channel output, if every translation track of the bone is a
+// pure spline and they all share the same key frames.
+fn cubic_translation(bone_tracks: &[Track], rest: [f32; 3]) -> Option<(Vec<f32>, Vec<f32>)> {
+    let mut components: [Option<Vec<SplineKey>>; 3] = [None, None, None];
+    for track in bone_tracks.iter().filter(|t| is_translation(t.r#type)) {
+        let component = match track.r#type {
+            TrackType::HSD_A_J_TRAX => 0,
+            TrackType::HSD_A_J_TRAY => 1,
+            _ => 2,
+        };
+        components[component] = Some(spline_keys(track)?);
+    }
+    let frames = components.iter().flatten().next()?.iter().map(|k| k.0).collect::<Vec<_>>();
+    if components.iter().flatten().any(|keys| keys.iter().map(|k| k.0).ne(frames.iter().cloned())) {
+        return None;
+    }
+    let times = frames.iter().map(|f| f / FPS).collect();
+    let mut output = Vec::with_capacity(frames.len() * 9);
+    for i in 0..frames.len() {
+        let mut in_tangent = [0.; 3];
+        let mut value = rest;
+        let mut out_tangent = [0.; 3];
+        for (c, keys) in components.iter().enumerate() {
+            if let Some(keys) = keys {
+                in_tangent[c] = keys[i].2 * FPS;
+                value[c] = keys[i].1;
+                out_tangent[c] = keys[i].3 * FPS;
+            }
+        }
+        output.extend_from_slice(&in_tangent);
+        output.extend_from_slice(&value);
+        output.extend_from_slice(&out_tangent);
+    }
+    Some((times, output))
+}
+
+fn write_animation(builder: &mut Builder, model: &Model, name: &str, animation: &Animation) -> std::io::Result<String> {
+    let last_frame = animation.frame_count.ceil().max(0.) as usize;
+    let times = (0..=last_frame).map(|f| f as f32 / FPS).collect::<Vec<_>>();
+    let sampled_input = builder.push_floats(&times, 1, None, true)?;
+
+    let mut translations = vec![vec![]; model.bones.len()];
+    let mut rotations = vec![vec![]; model.bones.len()];
+    let mut pose = animation.model.clone();
+    for frame in 0..=last_frame {
+        pose.update_joints(frame as f32);
+        for (i, bone) in pose.bones.iter().enumerate() {
+            let t = bone.local_translation();
+            translations[i].extend_from_slice(&[t.x, t.y, t.z]);
+            let mut q = bone.local_rotation().into_inner().coords;
+            let len = rotations[i].len();
+            if len >= 4 {
+                let previous: &[f32] = &rotations[i][len - 4..];
+                if q.x * previous[0] + q.y * previous[1] + q.z * previous[2] + q.w * previous[3] < 0. {
+                    q = -q;
+                }
+            }
+            rotations[i].extend_from_slice(&[q.x, q.y, q.z, q.w]);
+        }
+    }
+
+    let (mut samplers, mut channels) = (vec![], vec![]);
+    for (i, bone) in animation.model.bones.iter().enumerate() {
+        let node = match model.indexes.get(&bone.index) {
+            Some(node) => *node,
+            None => continue,
+        };
+        if bone.tracks.iter().any(|t| is_translation(t.r#type)) {
+            let j = &bone.joint;
+            let sampler = match cubic_translation(&bone.tracks, [j.tx, j.ty, j.tz]) {
+                Some((times, output)) => {
+                    let input = builder.push_floats(&times, 1, None, true)?;
+                    let output = builder.push_floats(&output, 3, None, false)?;
+                    format!("{{\"input\":{},\"output\":{},\"interpolation\":\"CUBICSPLINE\"}}", input, output)
+                },
+                None => {
+                    let output = builder.push_floats(&translations[i], 3, None, false)?;
+                    format!("{{\"input\":{},\"output\":{},\"interpolation\":\"LINEAR\"}}", sampled_input, output)
+                },
+            };
+            channels.push(format!("{{\"sampler\":{},\"target\":{{\"node\":{},\"path\":\"translation\"}}}}", samplers.len(), node));
+            samplers.push(sampler);
+        }
+        if bone.tracks.iter().any(|t| is_rotation(t.r#type)) {
+            let output = builder.push_floats(&rotations[i], 4, None, false)?;
+            channels.push(format!("{{\"sampler\":{},\"target\":{{\"node\":{},\"path\":\"rotation\"}}}}", samplers.len(), node));
+            samplers.push(format!("{{\"input\":{},\"output\":{},\"interpolation\":\"LINEAR\"}}", sampled_input, output));
+        }
+    }
+    Ok(format!("{{\"name\":{},\"samplers\":[{}],\"channels\":[{}]}}", json_string(name), samplers.join(","), channels.join(",")))
+}
+
+pub fn write_glb<W: Write>(writer: &mut W, model: &Model, hurtboxes: &[Hurtbox], animations: &[(&str, &Animation)]) -> std::io::Result<()> {
+    let mut builder = Builder::new();
+    let mut nodes = vec![];
+    let mut children: Vec<Vec<usize>> = model.bones.iter()
+        .map(|bone| bone.childs.iter().filter_map(|child| model.indexes.get(child).cloned()).collect())
+        .collect();
+
+    let mut meshes = vec![];
+    let mut hurtbox_nodes = vec![];
+    for hurtbox in hurtboxes {
+        let bone = match model.indexes.get(&hurtbox.bone_index) {
+            Some(bone) => *bone,
+            None => continue,
+        };
+        let (positions, normals, indices) = capsule_mesh(hurtbox);
+        let position = builder.push_floats(&positions, 3, Some(ARRAY_BUFFER), true)?;
+        let normal = builder.push_floats(&normals, 3, Some(ARRAY_BUFFER), false)?;
+        let indices = builder.push_indices(&indices);
+        meshes.push(format!("{{\"primitives\":[{{\"attributes\":{{\"POSITION\":{},\"NORMAL\":{}}},\"indices\":{},\"material\":0}}]}}", position, normal, indices));
+        children[bone].push(model.bones.len() + hurtbox_nodes.len());
+        hurtbox_nodes.push(format!("{{\"name\":{},\"mesh\":{}}}", json_string(&format!("Hurtbox_{}", hurtbox_nodes.len())), meshes.len() - 1));
+    }
+
+    for (bone, children) in model.bones.iter().zip(&children) {
+        let t = bone.local_translation();
+        let q = bone.local_rotation().into_inner().coords;
+        let children = if children.is_empty() {
+            String::new()
+        } else {
+            format!(",\"children\":[{}]", children.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(","))
+        };
+        nodes.push(format!("{{\"name\":{},\"translation\":{},\"rotation\":{}{}}}",
+            json_string(&bone.name), json_floats(&[t.x, t.y, t.z])?, json_floats(&[q.x, q.y, q.z, q.w])?, children));
+    }
+    nodes.extend(hurtbox_nodes);
+    let roots = model.bones.iter().enumerate()
+        .filter(|(_, bone)| !model.indexes.contains_key(&bone.parent))
+        .map(|(i, _)| i.to_string())
+        .collect::<Vec<_>>();
+
+    let animations = animations.iter()
+        .map(|(name, animation)| write_animation(&mut builder, model, name, animation))
+        .collect::<std::io::Result<Vec<_>>>()?;
+
+    pad(&mut builder.bin, 0);
+    let mut json = format!(
+        "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"melee-anim-rs\"}},\"scene\":0,\"scenes\":[{{\"nodes\":[{}]}}],\"nodes\":[{}]",
+        roots.join(","), nodes.join(","));
+    if !meshes.is_empty() {
+        json.push_str(&format!(",\"meshes\":[{}],\"materials\":[{{\"name\":\"Hurtbox\",\"pbrMetallicRoughness\":{{\"baseColorFactor\":[1.0,1.0,0.0,0.4],\"metallicFactor\":0.0}},\"alphaMode\":\"BLEND\",\"doubleSided\":true}}]", meshes.join(",")));
+    }
+    if !animations.is_empty() {
+        json.push_str(&format!(",\"animations\":[{}]", animations.join(",")));
+    }
+    if !builder.bin.is_empty() {
+        json.push_str(&format!(",\"buffers\":[{{\"byteLength\":{}}}],\"bufferViews\":[{}],\"accessors\":[{}]",
+            builder.bin.len(), builder.buffer_views.join(","), builder.accessors.join(",")));
+    }
+    json.push('}');
+    let mut json = json.into_bytes();
+    pad(&mut json, b' ');
+
+    let bin_chunk = if builder.bin.is_empty() { 0 } else { 8 + builder.bin.len() };
+    let length = 12 + 8 + json.len() + bin_chunk;
+    writer.write_all(b"glTF")?;
+    writer.write_all(&2u32.to_le_bytes())?;
+    writer.write_all(&(length as u32).to_le_bytes())?;
+    writer.write_all(&(json.len() as u32).to_le_bytes())?;
+    writer.write_all(b"JSON")?;
+    writer.write_all(&json)?;
+    if !builder.bin.is_empty() {
+        writer.write_all(&(builder.bin.len() as u32).to_le_bytes())?;
+        writer.write_all(b"BIN\0")?;
+        writer.write_all(&builder.bin)?;
+    }
+    Ok(())
+}
+
+impl Animation {
+    pub fn write_glb<W: Write>(&self, writer: &mut W, name: &str) -> std::io::Result<()> {
+        write_glb(writer, &self.model, &self.hurtboxes, &[(name, self)])
+    }
+}
@@ -1,17 +1,22 @@
 use std::str::FromStr;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Cursor};
-use nalgebra::{Unit, Vector3};
+use nalgebra::{Point3, Unit, Vector3};
 use nalgebra::geometry::{UnitQuaternion, Isometry3, Translation3};
 
-#[derive(Debug)]
+use crate::error::{parse_f32, parse_i32, Cause, Error, Input, ParseMode};
+use crate::Source;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HurtboxType {
     Low,
     Mid,
     High,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hurtbox {
     pub bone_index: i32,
     pub x1: f32,
@@ -25,40 +30,37 @@ pub struct Hurtbox {
     pub grabable: bool,
 }
 
-#[derive(Debug, Clone)]
-pub struct ParseHurtboxError;
-
-impl From<std::num::ParseIntError> for ParseHurtboxError {
-    fn from(_: std::num::ParseIntError) -> Self {
-        ParseHurtboxError{}
-    }
-}
-
-impl From<std::num::ParseFloatError> for ParseHurtboxError {
-    fn from(_: std::num::ParseFloatError) -> Self {
-        ParseHurtboxError{}
-    }
-}
-
-impl From<std::str::ParseBoolError> for ParseHurtboxError {
-    fn from(_: std::str::ParseBoolError) -> Self {
-        ParseHurtboxError{}
-    }
+// A hurtbox placed in the world by the transform of its bone.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct WorldHurtbox {
+    pub bone_index: i32,
+    pub p1: Point3<f32>,
+    pub p2: Point3<f32>,
+    pub size: f32,
+    pub r#type: HurtboxType,
+    pub grabable: bool,
 }
 
-impl From<std::io::Error> for ParseHurtboxError {
-    fn from(_: std::io::Error) -> Self {
-        ParseHurtboxError{}
+impl Default for WorldHurtbox {
+    fn default() -> Self {
+        WorldHurtbox {
+            bone_index: 0,
+            p1: Point3::origin(),
+            p2: Point3::origin(),
+            size: 0.,
+            r#type: HurtboxType::Low,
+            grabable: false,
+        }
     }
 }
 
 impl Hurtbox {
-    pub fn parse_type(s: &str) -> Result<HurtboxType, ParseHurtboxError> {
+    pub fn parse_type(s: &str) -> Result<HurtboxType, Error> {
         match s {
             "Low" => Ok(HurtboxType::Low),
             "Mid" => Ok(HurtboxType::Mid),
             "High" => Ok(HurtboxType::High),
-            _ => Err(ParseHurtboxError),
+            _ => Err(Error::parse(Input::Hurtbox, s, Cause::UnknownHurtboxType)),
         }
     }
     pub fn norm(&self) -> f32 {
@@ -94,48 +96,111 @@ impl Hurtbox {
     pub fn transform(&self) -> Isometry3<f32> {
         Isometry3::from_parts(self.center(), self.rotation())
     }
+
+    pub fn world(&self, bone_transform: &Isometry3<f32>) -> WorldHurtbox {
+        WorldHurtbox {
+            bone_index: self.bone_index,
+            p1: bone_transform * self.p1() * Point3::origin(),
+            p2: bone_transform * self.p2() * Point3::origin(),
+            size: self.size,
+            r#type: self.r#type,
+            grabable: self.grabable,
+        }
+    }
 }
 
 impl FromStr for Hurtbox {
-    type Err = ParseHurtboxError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let hb_data = s.split(",").collect::<Vec<_>>();
+        let hb_data = s.trim().split(",").map(|field| field.trim()).collect::<Vec<_>>();
+        if hb_data.len() != 10 {
+            return Err(Error::parse(Input::Hurtbox, s, Cause::FieldCount {expected: 10, found: hb_data.len()}));
+        }
         Ok(Hurtbox {
-            bone_index: i32::from_str(hb_data[0])?,
-            x1: f32::from_str(hb_data[1])?,
-            y1: f32::from_str(hb_data[2])?,
-            z1: f32::from_str(hb_data[3])?,
-            x2: f32::from_str(hb_data[4])?,
-            y2: f32::from_str(hb_data[5])?,
-            z2: f32::from_str(hb_data[6])?,
-            size: f32::from_str(hb_data[7])?,
+            bone_index: parse_i32(Input::Hurtbox, hb_data[0])?,
+            x1: parse_f32(Input::Hurtbox, hb_data[1])?,
+            y1: parse_f32(Input::Hurtbox, hb_data[2])?,
+            z1: parse_f32(Input::Hurtbox, hb_data[3])?,
+            x2: parse_f32(Input::Hurtbox, hb_data[4])?,
+            y2: parse_f32(Input::Hurtbox, hb_data[5])?,
+            z2: parse_f32(Input::Hurtbox, hb_data[6])?,
+            size: parse_f32(Input::Hurtbox, hb_data[7])?,
             r#type: Hurtbox::parse_type(hb_data[8])?,
-            grabable: i32::from_str(hb_data[9])? != 0,
+            grabable: parse_i32(Input::Hurtbox, hb_data[9])? != 0,
         })
     }
 }
 
-pub fn parse_hurtboxes_from_path(path: &str) -> Result<Vec<Hurtbox>, ParseHurtboxError> {
-    let file = File::open(&path)?;
-    let reader = BufReader::new(file);
-    let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
-    parse_hurtboxes(&lines)
+pub fn parse_hurtboxes_from_path(path: &str) -> Result<Vec<Hurtbox>, Error> {
+    let file = File::open(path).map_err(|e| Error::io(Input::Hurtbox, e))?;
+    parse_hurtboxes_from_reader(BufReader::new(file), ParseMode::Lenient)
 }
 
-pub fn parse_hurtboxes_from_bytes(bytes: &[u8]) -> Result<Vec<Hurtbox>, ParseHurtboxError> {
-    let reader = Cursor::new(bytes);
-    let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
-    parse_hurtboxes(&lines)
+pub fn parse_hurtboxes_from_bytes(bytes: &[u8]) -> Result<Vec<Hurtbox>, Error> {
+    parse_hurtboxes_from_reader(Cursor::new(bytes), ParseMode::Lenient)
 }
 
-pub fn parse_hurtboxes(lines: &Vec<String>) -> Result<Vec<Hurtbox>, ParseHurtboxError> {
-    let mut hurtboxes: Vec<Hurtbox> = vec![];
-    for line in lines {
-        match Hurtbox::from_str(&line) {
-            Ok(hurtbox) => &hurtboxes.push(hurtbox),
-            Err(_) => &(),
-        };
+pub fn parse_hurtboxes_from_source(source: Source, mode: ParseMode) -> Result<Vec<Hurtbox>, Error> {
+    parse_hurtboxes_from_reader(source.reader(Input::Hurtbox)?, mode)
+}
+
+pub fn parse_hurtboxes_from_reader<R: BufRead>(reader: R, mode: ParseMode) -> Result<Vec<Hurtbox>, Error> {
+    let mut parser = HurtboxParser::new(mode);
+    crate::read_lines(reader, Input::Hurtbox, |number, line| {
+        parser.feed(number, line);
+        Ok(true)
+    })?;
+    parser.finish()
+}
+
+pub fn parse_hurtboxes(lines: &[String]) -> Result<Vec<Hurtbox>, Error> {
+    parse_hurtboxes_with_mode(lines, ParseMode::Lenient)
+}
+
+fn is_header(line: &str) -> bool {
+    line.split(",").next().map(|field| field.trim()) == Some("BoneIndex")
+}
+
+pub fn parse_hurtboxes_with_mode(lines: &[String], mode: ParseMode) -> Result<Vec<Hurtbox>, Error> {
+    let mut parser = HurtboxParser::new(mode);
+    for (number, line) in lines.iter().enumerate() {
+        parser.feed(number + 1, line);
+    }
+    parser.finish()
+}
+
+struct HurtboxParser {
+    mode: ParseMode,
+    hurtboxes: Vec<Hurtbox>,
+    errors: Vec<Error>,
+    first_line: bool,
+}
+
+impl HurtboxParser {
+    fn new(mode: ParseMode) -> Self {
+        HurtboxParser {mode, hurtboxes: vec![], errors: vec![], first_line: true}
+    }
+
+    fn feed(&mut self, line_number: usize, line: &str) {
+        if line.trim().is_empty() {
+            return;
+        }
+        let header = self.first_line && is_header(line);
+        self.first_line = false;
+        match Hurtbox::from_str(line) {
+            Ok(hurtbox) => self.hurtboxes.push(hurtbox),
+            Err(error) => if self.mode == ParseMode::Strict && !header {
+                self.errors.push(error.at_line(line_number));
+            },
+        }
+    }
+
+    fn finish(self) -> Result<Vec<Hurtbox>, Error> {
+        if self.errors.is_empty() {
+            Ok(self.hurtboxes)
+        } else {
+            Err(Error::Multiple {input: Input::Hurtbox, errors: self.errors})
+        }
     }
-    Ok(hurtboxes)
 }
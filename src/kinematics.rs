@@ -0,0 +1,203 @@
+use nalgebra::{Point3, UnitQuaternion, Vector3};
+
+use crate::animation::{AnimState, Animation, Track, TrackType};
+use crate::curve::Mode;
+use crate::bone::Model;
+
+// Derivatives are taken with respect to the frame number: velocities are per frame and
+// accelerations per frame squared. Rotations use the joint convention of `Bone::local_rotation`.
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BoneMotion {
+    pub bone_index: i32,
+    pub position: Point3<f32>,
+    pub velocity: Vector3<f32>,
+    pub acceleration: Vector3<f32>,
+    pub angular_velocity: Vector3<f32>,
+    pub angular_acceleration: Vector3<f32>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PointMotion {
+    pub position: Point3<f32>,
+    pub velocity: Vector3<f32>,
+    pub acceleration: Vector3<f32>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct HurtboxMotion {
+    pub bone_index: i32,
+    pub p1: PointMotion,
+    pub p2: PointMotion,
+}
+
+// First and second derivatives of the joint components, in `Joint` field order.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+struct JointRates {
+    velocity: [f32; 6],
+    acceleration: [f32; 6],
+}
+
+impl AnimState {
+    // First and second derivative of `value` at `frame`.
+    pub fn derivatives(&self, frame: f32) -> (f32, f32) {
+        let (p0, p1, d0, d1, t0, t1) = (self.p0(), self.p1(), self.d0(), self.d1(), self.t0(), self.t1());
+        match self.mode() {
+            Mode::Constant => (0., 0.),
+            Mode::Linear => ((p1 - p0) / (t1 - t0), 0.),
+            Mode::Hermite => {
+                let l = t1 - t0;
+                let t = frame - t0;
+                let velocity = d1 * (3. * t * t / (l * l) - 2. * t / l)
+                    + d0 * (1. + 3. * t * t / (l * l) - 4. * t / l)
+                    + (p0 - p1) * (6. * t * t / (l * l * l) - 6. * t / (l * l));
+                let acceleration = d1 * (6. * t / (l * l) - 2. / l)
+                    + d0 * (6. * t / (l * l) - 4. / l)
+                    + (p0 - p1) * (12. * t / (l * l * l) - 6. / (l * l));
+                (velocity, acceleration)
+            },
+        }
+    }
+}
+
+impl Track {
+    // First and second derivative of `get_value` at `frame`. Where two segments meet, the
+    // segment starting at `frame` is used.
+    pub fn get_derivatives(&self, frame: f32) -> (f32, f32) {
+        self.get_anim_state(frame).derivatives(frame)
+    }
+
+    pub fn get_velocity(&self, frame: f32) -> f32 {
+        self.get_derivatives(frame).0
+    }
+
+    pub fn get_acceleration(&self, frame: f32) -> f32 {
+        self.get_derivatives(frame).1
+    }
+}
+
+fn joint_rates(animation: &Animation, frame: f32) -> Vec<JointRates> {
+    animation.model.bones.iter().map(|bone| {
+        let mut rates = JointRates::default();
+        for track in &bone.tracks {
+            let component = match track.r#type {
+                TrackType::HSD_A_J_TRAX => 0,
+                TrackType::HSD_A_J_TRAY => 1,
+                TrackType::HSD_A_J_TRAZ => 2,
+                TrackType::HSD_A_J_ROTX => 3,
+                TrackType::HSD_A_J_ROTY => 4,
+                TrackType::HSD_A_J_ROTZ => 5,
+                _ => continue,
+            };
+            let (velocity, acceleration) = track.get_derivatives(frame);
+            rates.velocity[component] = velocity;
+            rates.acceleration[component] = acceleration;
+        }
+        rates
+    }).collect()
+}
+
+fn propagate(model: &Model, rates: &[JointRates], index: usize, parent: &BoneMotion, parent_rotation: &UnitQuaternion<f32>, motions: &mut [Option<BoneMotion>]) {
+    let bone = &model.bones[index];
+    let rate = &rates[index];
+    let joint = &bone.joint;
+
+    // Angular velocity and acceleration of the joint rotation Rz * Ry * Rx, in the parent frame
+    let rz = UnitQuaternion::from_euler_angles(0., 0., joint.rz);
+    let rzy = UnitQuaternion::from_euler_angles(0., joint.ry, joint.rz);
+    let z = Vector3::z();
+    let y = rz * Vector3::y();
+    let x = rzy * Vector3::x();
+    let [_, _, _, vrx, vry, vrz] = rate.velocity;
+    let [_, _, _, arx, ary, arz] = rate.acceleration;
+    let local_angular_velocity = z * vrz + y * vry + x * vrx;
+    let dy = (z * vrz).cross(&y);
+    let dx = (z * vrz + y * vry).cross(&x);
+    let local_angular_acceleration = z * arz + y * ary + dy * vry + x * arx + dx * vrx;
+
+    let offset = parent_rotation * Vector3::new(joint.tx, joint.ty, joint.tz);
+    let offset_velocity = parent_rotation * Vector3::new(rate.velocity[0], rate.velocity[1], rate.velocity[2]);
+    let offset_acceleration = parent_rotation * Vector3::new(rate.acceleration[0], rate.acceleration[1], rate.acceleration[2]);
+    let angular_velocity = parent.angular_velocity + parent_rotation * local_angular_velocity;
+    let angular_acceleration = parent.angular_acceleration + parent_rotation * local_angular_acceleration
+        + parent.angular_velocity.cross(&(parent_rotation * local_angular_velocity));
+    let w = parent.angular_velocity;
+    let motion = BoneMotion {
+        bone_index: bone.index,
+        position: bone.transform() * Point3::origin(),
+        velocity: parent.velocity + w.cross(&offset) + offset_velocity,
+        acceleration: parent.acceleration + parent.angular_acceleration.cross(&offset) + w.cross(&w.cross(&offset))
+            + 2. * w.cross(&offset_velocity) + offset_acceleration,
+        angular_velocity,
+        angular_acceleration,
+    };
+    motions[index] = Some(motion);
+
+    let rotation = bone.rotation();
+    for child in &bone.childs {
+        if let Some(child_index) = model.indexes.get(child) {
+            propagate(model, rates, *child_index, &motion, &rotation, motions);
+        }
+    }
+}
+
+impl BoneMotion {
+    // Motion of a point fixed in the bone's local frame.
+    pub fn point(&self, rotation: &UnitQuaternion<f32>, local: &Vector3<f32>) -> PointMotion {
+        let r = rotation * local;
+        let w = self.angular_velocity;
+        PointMotion {
+            position: self.position + r,
+            velocity: self.velocity + w.cross(&r),
+            acceleration: self.acceleration + self.angular_acceleration.cross(&r) + w.cross(&w.cross(&r)),
+        }
+    }
+}
+
+impl Animation {
+    fn motions(&self, frame: f32) -> (Model, Vec<BoneMotion>) {
+        let model = self.get_frame_model(frame);
+        let rates = joint_rates(self, frame);
+        let mut motions = vec![None; model.bones.len()];
+        if let Some(root) = model.indexes.get(&model.root_bone_index) {
+            let rest = BoneMotion {
+                bone_index: -1,
+                position: Point3::origin(),
+                velocity: Vector3::zeros(),
+                acceleration: Vector3::zeros(),
+                angular_velocity: Vector3::zeros(),
+                angular_acceleration: Vector3::zeros(),
+            };
+            propagate(&model, &rates, *root, &rest, &UnitQuaternion::identity(), &mut motions);
+        }
+        let motions = motions.into_iter().zip(&model.bones).map(|(motion, bone)| motion.unwrap_or(BoneMotion {
+            bone_index: bone.index,
+            position: Point3::origin(),
+            velocity: Vector3::zeros(),
+            acceleration: Vector3::zeros(),
+            angular_velocity: Vector3::zeros(),
+            angular_acceleration: Vector3::zeros(),
+        })).collect();
+        (model, motions)
+    }
+
+    // World motion of every bone at `frame`, in the order of the model's bones.
+    pub fn bone_motions(&self, frame: f32) -> Vec<BoneMotion> {
+        self.motions(frame).1
+    }
+
+    // World motion of both endpoints of every hurtbox whose bone is in the model.
+    pub fn hurtbox_motions(&self, frame: f32) -> Vec<HurtboxMotion> {
+        let (model, motions) = self.motions(frame);
+        self.hurtboxes.iter().filter_map(|hb| {
+            let index = *model.indexes.get(&hb.bone_index)?;
+            let rotation = model.bones[index].rotation();
+            let motion = &motions[index];
+            Some(HurtboxMotion {
+                bone_index: hb.bone_index,
+                p1: motion.point(&rotation, &Vector3::new(hb.x1, hb.y1, hb.z1)),
+                p2: motion.point(&rotation, &Vector3::new(hb.x2, hb.y2, hb.z2)),
+            })
+        }).collect()
+    }
+}
@@ -5,63 +5,80 @@ use std::io::{BufRead, BufReader, Cursor};
 pub mod bone;
 pub mod hurtbox;
 pub mod animation;
+pub mod gltf;
+pub mod bvh;
+pub mod dat;
+pub mod error;
+pub mod figatree;
+pub mod cache;
+pub mod embed;
+pub mod frames;
+pub mod batch;
+pub mod compiled;
+pub mod kinematics;
+pub mod curve;
+pub mod edit;
+pub mod fit;
+pub mod tangent;
+pub mod retime;
+pub mod retarget;
 
-use bone::{Model, ParseSMDError};
-use hurtbox::ParseHurtboxError;
-use animation::{Animation, ParseFigaTreeError};
+use bone::Model;
+use animation::Animation;
+use error::{Error, Input, ParseMode};
+use figatree::Diagnostic;
 
-#[derive(Debug, Clone)]
-pub struct LoadAnimationError;
-
-impl From<std::io::Error> for LoadAnimationError {
-    fn from(_: std::io::Error) -> Self {
-        LoadAnimationError{}
+// Feeds `f` each line with its 1-based number, reusing a single buffer. `f` returns false to stop reading.
+pub(crate) fn read_lines<R, F>(mut reader: R, input: Input, mut f: F) -> Result<(), Error>
+where R: BufRead, F: FnMut(usize, &str) -> Result<bool, Error> {
+    let mut buffer = String::new();
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer).map_err(|e| Error::io(input, e))? == 0 {
+            return Ok(());
+        }
+        number += 1;
+        let line = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if !f(number, line)? {
+            return Ok(());
+        }
     }
 }
 
-impl From<ParseSMDError> for LoadAnimationError {
-    fn from(_: ParseSMDError) -> Self {
-        LoadAnimationError{}
-    }
+// Where loader input comes from. Text and bytes are content, never file names.
+pub enum Source<'a> {
+    Path(&'a str),
+    Text(&'a str),
+    Bytes(&'a [u8]),
+    Reader(Box<dyn BufRead + 'a>),
 }
 
-impl From<ParseHurtboxError> for LoadAnimationError {
-    fn from(_: ParseHurtboxError) -> Self {
-        LoadAnimationError{}
+impl<'a> Source<'a> {
+    pub fn reader(self, input: Input) -> Result<Box<dyn BufRead + 'a>, Error> {
+        match self {
+            Source::Path(path) => {
+                let file = File::open(path).map_err(|e| Error::io(input, e))?;
+                Ok(Box::new(BufReader::new(file)))
+            },
+            Source::Text(text) => Ok(Box::new(Cursor::new(text.as_bytes()))),
+            Source::Bytes(bytes) => Ok(Box::new(Cursor::new(bytes))),
+            Source::Reader(reader) => Ok(reader),
+        }
     }
 }
 
-impl From<ParseFigaTreeError> for LoadAnimationError {
-    fn from(_: ParseFigaTreeError) -> Self {
-        LoadAnimationError{}
-    }
+// Drops the diagnostics of the figatree load, see `get_animation_with_diagnostics` to keep them.
+pub fn get_animation_with_hurtboxes(model_data: Source, hurtboxes_data: Source, animation_data: Source) -> Result<Animation, Error> {
+    Ok(get_animation_with_diagnostics(model_data, hurtboxes_data, animation_data)?.0)
 }
 
-
-pub trait Data {
-    fn read(&self) -> Result<Vec<String>, std::io::Error>;
-}
-
-impl Data for &str {
-    fn read(&self) -> Result<Vec<String>, std::io::Error> {
-        let file = File::open(self)?;
-        let reader = BufReader::new(file);
-        reader.lines().collect::<Result<Vec<_>, _>>()
-    }
-}
-
-impl Data for &[u8] {
-    fn read(&self) -> Result<Vec<String>, std::io::Error> {
-        let reader = Cursor::new(self);
-        reader.lines().collect::<Result<Vec<_>, _>>()
-    }
-}
-
-pub fn get_animation_with_hurtboxes<T: Data>(model_data: T, hurtboxes_data: T, animation_data: T) -> Result<Animation, LoadAnimationError> {
-    let model = Model::from_smd(&model_data.read()?)?;
-    let hurtboxes = hurtbox::parse_hurtboxes(&hurtboxes_data.read()?)?;
-    let mut anim = Animation::from_model(model.clone());
-    anim.load_figatree(&animation_data.read()?)?;
+pub fn get_animation_with_diagnostics(model_data: Source, hurtboxes_data: Source, animation_data: Source) -> Result<(Animation, Vec<Diagnostic>), Error> {
+    let model = Model::from_smd_source(model_data)?;
+    let hurtboxes = hurtbox::parse_hurtboxes_from_source(hurtboxes_data, ParseMode::Lenient)?;
+    let mut anim = Animation::from_model(model);
+    let diagnostics = anim.load_figatree_from_source(animation_data, ParseMode::Lenient)?;
     anim.attach_hurtboxes(hurtboxes);
-    Ok(anim)
-}
\ No newline at end of file
+    Ok((anim, diagnostics))
+}
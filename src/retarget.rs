@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use nalgebra::Vector3;
+
+use crate::animation::{Animation, Key, Track, TrackType};
+use crate::bone::{Bone, Model};
+use crate::fit::FitError;
+use crate::frames::frame_count;
+
+// Retargeting moves the tracks of each mapped source bone to its target bone. Translations are
+// scaled by the ratio of the bones' rest offsets from their parents, or by the ratio of the whole
+// skeletons for bones without one such as the root. Target bones without a source keep their
+// rest pose, and the result has no hurtboxes: set the target character's ones.
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum RetargetError {
+    UnknownSourceBone(i32),
+    UnknownTargetBone(i32),
+    UnknownBoneName(String),
+    // Two source bones mapped to the same target bone
+    DuplicateTarget(i32),
+    // Refitting the rest-relative rotations failed
+    Fit(FitError),
+}
+
+impl fmt::Display for RetargetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RetargetError::UnknownSourceBone(index) => write!(f, "no source bone with index {}", index),
+            RetargetError::UnknownTargetBone(index) => write!(f, "no target bone with index {}", index),
+            RetargetError::UnknownBoneName(name) => write!(f, "no bone named {}", name),
+            RetargetError::DuplicateTarget(index) => write!(f, "target bone {} is mapped more than once", index),
+            RetargetError::Fit(e) => write!(f, "failed to fit rotations: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RetargetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RetargetError::Fit(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<FitError> for RetargetError {
+    fn from(e: FitError) -> Self {
+        RetargetError::Fit(e)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Rotation {
+    // Copies the rotation tracks, for skeletons whose bones share rest orientations
+    Copy,
+    // Applies each source rotation relative to the source rest pose on top of the target rest
+    // pose, refitting keys within `tolerance` of every frame
+    RestRelative { tolerance: f32 },
+}
+
+// Source bone index to target bone index.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct BoneMap {
+    pairs: BTreeMap<i32, i32>,
+}
+
+impl BoneMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Pairs the bones with the same index in both models.
+    pub fn by_index(source: &Model, target: &Model) -> Self {
+        let pairs = source.bones.iter().filter(|bone| target.indexes.contains_key(&bone.index)).map(|bone| (bone.index, bone.index)).collect();
+        BoneMap {pairs}
+    }
+
+    // Pairs the bones with the same name in both models.
+    pub fn by_name(source: &Model, target: &Model) -> Self {
+        let pairs = source.bones.iter().filter_map(|bone| {
+            let other = target.bones.iter().find(|other| other.name == bone.name)?;
+            Some((bone.index, other.index))
+        }).collect();
+        BoneMap {pairs}
+    }
+
+    // Pairs the bones named in `names`, source name first.
+    pub fn from_names(source: &Model, target: &Model, names: &[(&str, &str)]) -> Result<Self, RetargetError> {
+        let index = |model: &Model, name: &str| {
+            model.bones.iter().find(|bone| bone.name == name).map(|bone| bone.index).ok_or_else(|| RetargetError::UnknownBoneName(name.to_string()))
+        };
+        let mut map = BoneMap::new();
+        for (source_name, target_name) in names {
+            map.insert(index(source, source_name)?, index(target, target_name)?);
+        }
+        Ok(map)
+    }
+
+    // Maps a source bone, returning the target bone it was mapped to before.
+    pub fn insert(&mut self, source: i32, target: i32) -> Option<i32> {
+        self.pairs.insert(source, target)
+    }
+
+    pub fn remove(&mut self, source: i32) -> Option<i32> {
+        self.pairs.remove(&source)
+    }
+
+    pub fn target(&self, source: i32) -> Option<i32> {
+        self.pairs.get(&source).copied()
+    }
+
+    pub fn pairs(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.pairs.iter().map(|(source, target)| (*source, *target))
+    }
+
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+}
+
+fn limb_length(bone: &Bone) -> f32 {
+    Vector3::new(bone.joint.tx, bone.joint.ty, bone.joint.tz).norm()
+}
+
+fn scaled(track: &Track, ratio: f32) -> Track {
+    let keys = track.keys.iter().map(|key| Key::new(key.frame(), key.value() * ratio, key.tan() * ratio, key.interpolation_type())).collect();
+    Track {r#type: track.r#type, keys}
+}
+
+fn is_rotation(r#type: TrackType) -> bool {
+    matches!(r#type, TrackType::HSD_A_J_ROTX | TrackType::HSD_A_J_ROTY | TrackType::HSD_A_J_ROTZ)
+}
+
+fn is_translation(r#type: TrackType) -> bool {
+    matches!(r#type, TrackType::HSD_A_J_TRAX | TrackType::HSD_A_J_TRAY | TrackType::HSD_A_J_TRAZ)
+}
+
+// Angle closest to `previous` among the turns of `angle`, so that fitted tracks don't jump.
+fn unwrap(angle: f32, previous: f32) -> f32 {
+    let turn = std::f32::consts::TAU;
+    angle - ((angle - previous) / turn).round() * turn
+}
+
+// Rotation tracks for each mapped source bone with one, as per-frame Euler angles of the source
+// rotation relative to its rest pose applied to the target rest pose.
+fn rest_relative_rotations(animation: &Animation, target: &Model, map: &BoneMap, tolerance: f32) -> Result<BTreeMap<i32, Vec<Track>>, FitError> {
+    let bones = animation.model.bones.iter().filter(|bone| bone.tracks.iter().any(|track| is_rotation(track.r#type)))
+        .filter_map(|bone| {
+            let target_bone = &target.bones[*target.indexes.get(&map.target(bone.index)?)?];
+            Some((bone.index, target_bone.index, bone.local_rotation().inverse(), target_bone.local_rotation()))
+        }).collect::<Vec<_>>();
+
+    let mut model = animation.model.clone();
+    let mut samples = vec![[vec![], vec![], vec![]]; bones.len()];
+    for frame in 0..frame_count(animation) {
+        model.update_joints(frame as f32);
+        for ((source, _, source_rest_inverse, target_rest), samples) in bones.iter().zip(&mut samples) {
+            let rotation = target_rest * source_rest_inverse * model.bones[model.indexes[source]].local_rotation();
+            let (rx, ry, rz) = rotation.euler_angles();
+            for (angles, angle) in samples.iter_mut().zip([rx, ry, rz]) {
+                let angle = angles.last().map_or(angle, |previous| unwrap(angle, *previous));
+                angles.push(angle);
+            }
+        }
+    }
+
+    bones.iter().zip(samples).map(|((_, target_index, _, _), [rx, ry, rz])| {
+        let tracks = vec![
+            Track::from_samples(TrackType::HSD_A_J_ROTX, &rx, tolerance)?,
+            Track::from_samples(TrackType::HSD_A_J_ROTY, &ry, tolerance)?,
+            Track::from_samples(TrackType::HSD_A_J_ROTZ, &rz, tolerance)?,
+        ];
+        Ok((*target_index, tracks))
+    }).collect()
+}
+
+impl Animation {
+    // Runs this animation on the `target` skeleton.
+    pub fn retarget(&self, target: &Model, map: &BoneMap, rotation: Rotation) -> Result<Animation, RetargetError> {
+        let mut targets = vec![];
+        for (source, target_index) in map.pairs() {
+            if !self.model.indexes.contains_key(&source) {
+                return Err(RetargetError::UnknownSourceBone(source));
+            }
+            if !target.indexes.contains_key(&target_index) {
+                return Err(RetargetError::UnknownTargetBone(target_index));
+            }
+            if targets.contains(&target_index) {
+                return Err(RetargetError::DuplicateTarget(target_index));
+            }
+            targets.push(target_index);
+        }
+
+        let source_bone = |index: i32| &self.model.bones[self.model.indexes[&index]];
+        let target_bone = |index: i32| &target.bones[target.indexes[&index]];
+        let (source_length, target_length) = map.pairs()
+            .fold((0., 0.), |(s, t), (source, target)| (s + limb_length(source_bone(source)), t + limb_length(target_bone(target))));
+        let skeleton_ratio = if source_length > f32::EPSILON { target_length / source_length } else { 1. };
+
+        let mut rotations = match rotation {
+            Rotation::Copy => BTreeMap::new(),
+            Rotation::RestRelative {tolerance} => rest_relative_rotations(self, target, map, tolerance)?,
+        };
+
+        let mut model = target.clone();
+        for bone in &mut model.bones {
+            bone.tracks.clear();
+        }
+        for (source, target_index) in map.pairs() {
+            let source = source_bone(source);
+            let length = limb_length(source);
+            let ratio = if length > f32::EPSILON { limb_length(target_bone(target_index)) / length } else { skeleton_ratio };
+            let mut tracks = source.tracks.iter().filter_map(|track| {
+                if is_translation(track.r#type) {
+                    Some(scaled(track, ratio))
+                } else if is_rotation(track.r#type) && rotation != Rotation::Copy {
+                    None
+                } else {
+                    Some(track.clone())
+                }
+            }).collect::<Vec<_>>();
+            tracks.extend(rotations.remove(&target_index).unwrap_or_default());
+            model.bones[model.indexes[&target_index]].tracks = tracks;
+        }
+
+        Ok(Animation {frame_count: self.frame_count, model, hurtboxes: vec![]})
+    }
+}
@@ -0,0 +1,296 @@
+use std::fmt;
+
+use crate::animation::{Animation, InterpolationType, Key, Track};
+use crate::curve::Mode;
+use crate::fit::{fit_keys, FitError};
+use crate::frames::frame_count;
+
+// Retiming maps every source frame to a new frame through straight lines between anchors.
+// Each track is cut at its breakpoints and at the anchors, so that every piece is a single
+// constant, linear or hermite curve mapped by a single line, and is then written back as keys
+// with its tangents divided by the slope of that line. Frames outside the anchors are dropped.
+//
+// A reversed step happens at the same mapped frame, but holds the value from before the step.
+//
+// Keys land on fractional frames unless every anchor maps whole frames to whole frames, and
+// figatree data only stores whole frames. `resample` fits keys on whole frames again.
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RetimeError {
+    TooFewAnchors,
+    // Anchors need finite frames, increasing source frames and strictly increasing or
+    // decreasing target frames
+    InvalidAnchor(f32, f32),
+    // The earliest target frame must be 0
+    TargetStart(f32),
+}
+
+impl fmt::Display for RetimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RetimeError::TooFewAnchors => write!(f, "a frame map needs at least two anchors"),
+            RetimeError::InvalidAnchor(source, target) => write!(f, "invalid anchor from frame {} to frame {}", source, target),
+            RetimeError::TargetStart(frame) => write!(f, "retimed animation starts at frame {} instead of 0", frame),
+        }
+    }
+}
+
+impl std::error::Error for RetimeError {}
+
+// Source frame to target frame pairs, sorted by source frame.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FrameMap {
+    anchors: Vec<(f32, f32)>,
+}
+
+impl FrameMap {
+    pub fn new(anchors: Vec<(f32, f32)>) -> Result<Self, RetimeError> {
+        if anchors.len() < 2 {
+            return Err(RetimeError::TooFewAnchors);
+        }
+        let increasing = anchors[1].1 > anchors[0].1;
+        for (i, (source, target)) in anchors.iter().enumerate() {
+            let ordered = i == 0 || {
+                let (previous_source, previous_target) = anchors[i - 1];
+                *source > previous_source && if increasing { *target > previous_target } else { *target < previous_target }
+            };
+            if !source.is_finite() || !target.is_finite() || *source < 0. || !ordered {
+                return Err(RetimeError::InvalidAnchor(*source, *target));
+            }
+        }
+        let start = anchors.iter().map(|a| a.1).fold(f32::INFINITY, f32::min);
+        if start != 0. {
+            return Err(RetimeError::TargetStart(start));
+        }
+        Ok(FrameMap {anchors})
+    }
+
+    pub fn scale(frame_count: f32, factor: f32) -> Result<Self, RetimeError> {
+        FrameMap::new(vec![(0., 0.), (frame_count, frame_count * factor)])
+    }
+
+    pub fn reverse(frame_count: f32) -> Result<Self, RetimeError> {
+        FrameMap::new(vec![(0., frame_count), (frame_count, 0.)])
+    }
+
+    // Keeps the source frames from `start` to `end`, moved to start at frame 0.
+    pub fn trim(start: f32, end: f32) -> Result<Self, RetimeError> {
+        FrameMap::new(vec![(start, 0.), (end, end - start)])
+    }
+
+    pub fn anchors(&self) -> &[(f32, f32)] {
+        &self.anchors
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.anchors[1].1 < self.anchors[0].1
+    }
+
+    // First and last source frames kept.
+    pub fn source_range(&self) -> (f32, f32) {
+        (self.anchors[0].0, self.anchors[self.anchors.len() - 1].0)
+    }
+
+    // Frame count of the retimed animation.
+    pub fn frame_count(&self) -> f32 {
+        self.anchors.iter().map(|a| a.1).fold(0., f32::max)
+    }
+
+    // Slope of the line between the anchors around `frame`, the same for every frame in between.
+    fn slope(&self, frame: f32) -> f32 {
+        let index = self.anchors.partition_point(|a| a.0 <= frame).clamp(1, self.anchors.len() - 1);
+        let ((source, target), (next_source, next_target)) = (self.anchors[index - 1], self.anchors[index]);
+        (next_target - target) / (next_source - source)
+    }
+
+    // Target frame of a source frame, none for frames that are dropped. Use it to move events
+    // such as hitboxes along with the animation.
+    pub fn map(&self, frame: f32) -> Option<f32> {
+        let index = self.anchors.partition_point(|a| a.0 <= frame);
+        if index == 0 {
+            return None;
+        }
+        let (source, target) = self.anchors[index - 1];
+        if frame == source {
+            return Some(target);
+        }
+        let (next_source, next_target) = *self.anchors.get(index)?;
+        Some(target + (frame - source) * (next_target - target) / (next_source - source))
+    }
+}
+
+// A single curve of the retimed track, from `start` to `end` in target frames.
+struct Piece {
+    start: f32,
+    end: f32,
+    mode: Mode,
+    start_value: f32,
+    end_value: f32,
+    leaving: f32,
+    arriving: f32,
+}
+
+fn pieces(track: &Track, map: &FrameMap) -> Vec<Piece> {
+    let (first, last) = map.source_range();
+    let mut cuts = track.breakpoints().into_iter().filter(|frame| first < *frame && *frame < last)
+        .chain(map.anchors.iter().map(|a| a.0)).collect::<Vec<_>>();
+    cuts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    cuts.dedup();
+
+    let (last_frame, last_frame_index) = track.last_frame();
+    let mut pieces = cuts.windows(2).map(|cut| {
+        let (a, b) = (cut[0], cut[1]);
+        let (start, end) = (map.map(a).unwrap_or_default(), map.map(b).unwrap_or_default());
+        // Past its last key a track holds that key's value
+        let (mode, va, vb, da, db) = if track.keys.len() > 1 && a >= last_frame {
+            let value = track.keys[last_frame_index].value();
+            (Mode::Constant, value, value, 0., 0.)
+        } else {
+            let state = track.get_anim_state(a);
+            let mode = state.mode();
+            let va = state.value(a);
+            let vb = if mode == Mode::Constant { va } else { state.value(b) };
+            (mode, va, vb, state.derivatives(a).0, state.derivatives(b).0)
+        };
+        let slope = map.slope(a);
+        if slope > 0. {
+            Piece {start, end, mode, start_value: va, end_value: vb, leaving: da / slope, arriving: db / slope}
+        } else {
+            Piece {start: end, end: start, mode, start_value: vb, end_value: va, leaving: db / slope, arriving: da / slope}
+        }
+    }).collect::<Vec<_>>();
+    if map.is_reversed() {
+        pieces.reverse();
+    }
+    // Holds of the same value only need their first key
+    pieces.dedup_by(|piece, previous| {
+        let merge = piece.mode == Mode::Constant && previous.mode == Mode::Constant && piece.start_value == previous.start_value;
+        if merge {
+            previous.end = piece.end;
+        }
+        merge
+    });
+    pieces
+}
+
+// Writes pieces back as keys. A spline key's tangent is both the end slope of the piece before
+// it and the start slope of its own piece. When they differ, an SLP key before the next spline
+// key sets the start slope, or a second key at the same frame does when no spline key follows.
+fn keys(pieces: &[Piece]) -> Vec<Key> {
+    let mut keys = vec![];
+    // End slope the next key provides for the spline piece before it
+    let mut arriving: Option<f32> = None;
+    // Start slope an SLP key sets before the next spline key
+    let mut leaving: Option<f32> = None;
+    // Whether a piece ends on a key of its own instead of the start key of the next piece,
+    // at the end of the track and where the value jumps
+    let closed = |i: usize| match pieces.get(i + 1) {
+        Some(next) => pieces[i].mode != Mode::Constant && pieces[i].end_value != next.start_value,
+        None => true,
+    };
+    for (i, piece) in pieces.iter().enumerate() {
+        match piece.mode {
+            Mode::Hermite => {
+                if let Some(tan) = leaving.take() {
+                    keys.push(Key::new(piece.start, 0., tan, InterpolationType::HSD_A_OP_SLP));
+                }
+                let tan = arriving.take().unwrap_or(piece.leaving);
+                keys.push(Key::new(piece.start, piece.start_value, tan, InterpolationType::HSD_A_OP_SPL));
+                if tan != piece.leaving {
+                    if closed(i) || pieces[i + 1].mode == Mode::Hermite {
+                        leaving = Some(piece.leaving);
+                    } else {
+                        keys.push(Key::new(piece.start, piece.start_value, piece.leaving, InterpolationType::HSD_A_OP_SPL));
+                    }
+                }
+            },
+            mode => {
+                if let Some(tan) = arriving.take() {
+                    keys.push(Key::new(piece.start, 0., tan, InterpolationType::HSD_A_OP_SLP));
+                }
+                let interpolation_type = if mode == Mode::Linear { InterpolationType::HSD_A_OP_LIN } else { InterpolationType::HSD_A_OP_CON };
+                keys.push(Key::new(piece.start, piece.start_value, 0., interpolation_type));
+            },
+        }
+        match (piece.mode, closed(i)) {
+            (Mode::Hermite, true) => {
+                if let Some(tan) = leaving.take() {
+                    keys.push(Key::new(piece.end, 0., tan, InterpolationType::HSD_A_OP_SLP));
+                }
+                keys.push(Key::new(piece.end, piece.end_value, piece.arriving, InterpolationType::HSD_A_OP_SPL));
+            },
+            (Mode::Hermite, false) => arriving = Some(piece.arriving),
+            (_, true) => keys.push(Key::new(piece.end, piece.end_value, 0., InterpolationType::HSD_A_OP_CON)),
+            (_, false) => (),
+        }
+    }
+    keys
+}
+
+impl Track {
+    pub fn retime(&mut self, map: &FrameMap) {
+        if !self.keys.is_empty() {
+            self.keys = keys(&pieces(self, map));
+        }
+    }
+
+    // Replaces the keys with keys on whole frames that stay within `tolerance` of the value at
+    // every frame before `frame_count`.
+    pub fn resample(&mut self, frame_count: usize, tolerance: f32) -> Result<(), FitError> {
+        if !self.keys.is_empty() {
+            let values = (0..frame_count).map(|frame| self.get_value(frame as f32)).collect::<Vec<_>>();
+            self.keys = fit_keys(&values, tolerance)?;
+        }
+        Ok(())
+    }
+}
+
+impl Animation {
+    pub fn retime(&mut self, map: &FrameMap) {
+        for bone in &mut self.model.bones {
+            for track in &mut bone.tracks {
+                track.retime(map);
+            }
+        }
+        self.frame_count = map.frame_count();
+    }
+
+    // Plays the animation `factor` times as long.
+    pub fn scale_time(&mut self, factor: f32) -> Result<FrameMap, RetimeError> {
+        let map = FrameMap::scale(self.frame_count, factor)?;
+        self.retime(&map);
+        Ok(map)
+    }
+
+    // Moves each anchor's source frame to its target frame, dropping frames outside the
+    // anchors. Include frame 0 and the frame count to keep the whole animation.
+    pub fn remap_time(&mut self, anchors: &[(f32, f32)]) -> Result<FrameMap, RetimeError> {
+        let map = FrameMap::new(anchors.to_vec())?;
+        self.retime(&map);
+        Ok(map)
+    }
+
+    pub fn reverse(&mut self) -> Result<FrameMap, RetimeError> {
+        let map = FrameMap::reverse(self.frame_count)?;
+        self.retime(&map);
+        Ok(map)
+    }
+
+    pub fn trim(&mut self, start: f32, end: f32) -> Result<FrameMap, RetimeError> {
+        let map = FrameMap::trim(start, end)?;
+        self.retime(&map);
+        Ok(map)
+    }
+
+    // Puts every key on a whole frame, as encoding needs, and rounds the frame count up to one.
+    pub fn resample(&mut self, tolerance: f32) -> Result<(), FitError> {
+        let frame_count = frame_count(self);
+        for bone in &mut self.model.bones {
+            for track in &mut bone.tracks {
+                track.resample(frame_count, tolerance)?;
+            }
+        }
+        self.frame_count = frame_count as f32;
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use crate::animation::{Animation, InterpolationType, Key, Track};
+
+// Fills the `tan` of spline keys from the keys around them. A spline key's `tan` is the slope at
+// which segments arrive at the key. Segments leave it with that slope too, unless an SLP key
+// placed just before the next spline key overrides it. Those SLP keys are filled with the leaving
+// slope, and one is added where the slopes differ and there is none. Before a constant or linear
+// key an SLP key sets the arriving slope instead, so a spline key followed by one of those always
+// leaves at its arriving slope. Slopes are in value per frame, like `tan`.
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TangentMode {
+    // Slope between the previous and the next key
+    CatmullRom,
+    // Catmull-Rom, flattened at local extrema and limited so that curves between keys stay
+    // monotonic and never overshoot them
+    Clamped,
+    Flat,
+    // Slopes of the straight lines to the previous and next keys
+    Linear,
+}
+
+fn is_value_key(key: &Key) -> bool {
+    matches!(key.interpolation_type(), InterpolationType::HSD_A_OP_CON | InterpolationType::HSD_A_OP_LIN
+        | InterpolationType::HSD_A_OP_SPL0 | InterpolationType::HSD_A_OP_SPL)
+}
+
+fn slope(from: &Key, to: &Key) -> f32 {
+    let frames = to.frame() - from.frame();
+    if frames > 0. { (to.value() - from.value()) / frames } else { 0. }
+}
+
+impl TangentMode {
+    // Arriving and leaving slopes at `key`, between its previous and next value keys.
+    pub fn tangents(&self, previous: Option<&Key>, key: &Key, next: Option<&Key>) -> (f32, f32) {
+        let arriving = previous.map(|previous| slope(previous, key));
+        let leaving = next.map(|next| slope(key, next));
+        // A key at either end of the track continues the slope on its only side
+        let (arriving, leaving) = match (arriving, leaving) {
+            (Some(arriving), Some(leaving)) => (arriving, leaving),
+            (Some(arriving), None) => (arriving, arriving),
+            (None, Some(leaving)) => (leaving, leaving),
+            (None, None) => (0., 0.),
+        };
+        match self {
+            TangentMode::CatmullRom => {
+                let tan = match (previous, next) {
+                    (Some(previous), Some(next)) => slope(previous, next),
+                    _ => arriving,
+                };
+                (tan, tan)
+            },
+            TangentMode::Clamped => {
+                let tan = if arriving * leaving <= 0. {
+                    0.
+                } else {
+                    let catmull_rom = TangentMode::CatmullRom.tangents(previous, key, next).0;
+                    let limit = 3. * arriving.abs().min(leaving.abs());
+                    catmull_rom.clamp(-limit, limit)
+                };
+                (tan, tan)
+            },
+            TangentMode::Flat => (0., 0.),
+            TangentMode::Linear => (arriving, leaving),
+        }
+    }
+}
+
+impl Track {
+    pub fn fill_tangents(&mut self, mode: TangentMode) {
+        let values = self.keys.iter().enumerate().filter(|(_, key)| is_value_key(key)).map(|(index, _)| index).collect::<Vec<_>>();
+        // SLP keys to add, as the index of the key they go before
+        let mut slopes = vec![];
+        for (i, index) in values.iter().enumerate() {
+            if self.keys[*index].interpolation_type() != InterpolationType::HSD_A_OP_SPL {
+                continue;
+            }
+            let previous = i.checked_sub(1).map(|i| &self.keys[values[i]]);
+            let next = values.get(i + 1).map(|next| &self.keys[*next]);
+            let (arriving, leaving) = mode.tangents(previous, &self.keys[*index], next);
+            self.keys[*index].set_tan(arriving);
+
+            // SLP keys before a following spline key set the slope leaving this one
+            if let Some(next) = values.get(i + 1) {
+                match self.keys[*next].interpolation_type() {
+                    InterpolationType::HSD_A_OP_SPL0 | InterpolationType::HSD_A_OP_SPL => {
+                        let mut found = false;
+                        for key in &mut self.keys[index + 1..*next] {
+                            if key.interpolation_type() == InterpolationType::HSD_A_OP_SLP {
+                                key.set_tan(leaving);
+                                found = true;
+                            }
+                        }
+                        if !found && leaving != arriving {
+                            slopes.push((*next, leaving));
+                        }
+                    },
+                    _ => (),
+                }
+            }
+        }
+        for (index, leaving) in slopes.into_iter().rev() {
+            let frame = self.keys[index].frame();
+            self.keys.insert(index, Key::new(frame, 0., leaving, InterpolationType::HSD_A_OP_SLP));
+        }
+    }
+}
+
+impl Animation {
+    pub fn fill_tangents(&mut self, mode: TangentMode) {
+        for bone in &mut self.model.bones {
+            for track in &mut bone.tracks {
+                track.fill_tangents(mode);
+            }
+        }
+    }
+}
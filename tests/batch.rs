@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use melee_anim_rs::batch::{evaluate, output_len, Job};
+use melee_anim_rs::hurtbox::parse_hurtboxes_from_path;
+use nalgebra::Isometry3;
+
+mod common;
+
+use common::{load, ASSETS};
+
+#[test]
+fn animations_without_hurtboxes_write_every_frame() {
+    let animation = Arc::new(load());
+    assert!(animation.hurtboxes.is_empty());
+    let jobs = [Job::new(animation.clone(), 0..4), Job::new(animation.clone(), 4..9)];
+    let (bones_len, hurtboxes_len) = output_len(&jobs);
+    assert_eq!(hurtboxes_len, 0);
+    let mut bones = vec![Isometry3::identity(); bones_len];
+    evaluate(&jobs, &mut bones, &mut []).unwrap();
+
+    let mut scratch = animation.model.clone();
+    for (frame, bones) in bones.chunks(animation.model.bones.len()).enumerate() {
+        animation.pose_into(frame as f32, &mut scratch);
+        for (bone, expected) in bones.iter().zip(&scratch.bones) {
+            assert_eq!(*bone, expected.transform(), "frame {}", frame);
+        }
+    }
+}
+
+#[test]
+fn hurtboxes_match_query_hurtboxes() {
+    let mut animation = load();
+    animation.hurtboxes = parse_hurtboxes_from_path(&format!("{}hurtboxes.csv", ASSETS)).unwrap();
+    let mut stray = animation.hurtboxes[0].clone();
+    stray.bone_index = 1000;
+    animation.hurtboxes.insert(1, stray);
+    let animation = Arc::new(animation);
+
+    let jobs = [Job::new(animation.clone(), 2..6)];
+    let (bones_len, hurtboxes_len) = output_len(&jobs);
+    let mut bones = vec![Isometry3::identity(); bones_len];
+    let mut hurtboxes = vec![animation.hurtboxes[0].world(&Isometry3::identity()); hurtboxes_len];
+    evaluate(&jobs, &mut bones, &mut hurtboxes).unwrap();
+    let count = animation.hurtboxes.len() - 1;
+    assert_eq!(hurtboxes.len(), 4 * count);
+
+    let mut scratch = animation.model.clone();
+    let mut expected = vec![];
+    for (frame, hurtboxes) in (2..6).zip(hurtboxes.chunks(count)) {
+        animation.query_hurtboxes_into(frame as f32, &mut scratch, &mut expected);
+        assert_eq!(hurtboxes, &expected[..], "frame {}", frame);
+    }
+}
@@ -0,0 +1,86 @@
+use melee_anim_rs::bvh::RotationOrder;
+use nalgebra::{UnitQuaternion, Vector3};
+
+mod common;
+
+use common::load;
+
+const ORDERS: [RotationOrder; 6] = [RotationOrder::XYZ, RotationOrder::XZY, RotationOrder::YXZ, RotationOrder::YZX, RotationOrder::ZXY, RotationOrder::ZYX];
+
+// Rotation of BVH channel angles in degrees, the first channel being the outermost rotation.
+fn rotation(names: &[&str], angles: &[f32]) -> UnitQuaternion<f32> {
+    names.iter().zip(angles).fold(UnitQuaternion::identity(), |rotation, (name, angle)| {
+        let axis = match *name {
+            "Xrotation" => Vector3::x_axis(),
+            "Yrotation" => Vector3::y_axis(),
+            _ => Vector3::z_axis(),
+        };
+        rotation * UnitQuaternion::from_axis_angle(&axis, angle.to_radians())
+    })
+}
+
+#[test]
+fn angles_rebuild_the_rotation() {
+    for order in ORDERS {
+        for i in 0..50 {
+            let i = i as f32;
+            let expected = UnitQuaternion::from_euler_angles(i * 0.37 - 3., i * 0.21 - 1.5, i * 0.53 + 0.2);
+            let names = order.axes().map(|axis| ["Xrotation", "Yrotation", "Zrotation"][axis]);
+            let actual = rotation(&names, &order.angles(&expected).map(f32::to_degrees));
+            assert!(actual.angle_to(&expected) < 1e-4, "{:?}: {} instead of {}", order, actual, expected);
+        }
+    }
+}
+
+#[test]
+fn motion_matches_the_hierarchy() {
+    let animation = load();
+    let mut pose = animation.model.clone();
+    for order in ORDERS {
+        let mut text = vec![];
+        animation.write_bvh(&mut text, order).unwrap();
+        let text = String::from_utf8(text).unwrap();
+        let (hierarchy, motion) = text.split_once("MOTION\n").unwrap();
+
+        // Bone and channel names in the order of the motion values
+        let mut joints = vec![];
+        for line in hierarchy.lines().map(str::trim) {
+            if let Some(name) = line.strip_prefix("ROOT ").or_else(|| line.strip_prefix("JOINT ")) {
+                joints.push((name, vec![]));
+            } else if let Some(channels) = line.strip_prefix("CHANNELS ") {
+                let channels = channels.split(' ').collect::<Vec<_>>();
+                assert_eq!(channels[0].parse::<usize>().unwrap(), channels.len() - 1);
+                joints.last_mut().unwrap().1 = channels[1..].to_vec();
+            }
+        }
+        assert_eq!(joints.len(), animation.model.bones.len());
+        let channel_count = joints.iter().map(|joint| joint.1.len()).sum::<usize>();
+        let bones = joints.iter().map(|(name, _)| {
+            animation.model.bones.iter().position(|bone| bone.name.split_whitespace().collect::<Vec<_>>().join("_") == *name).unwrap()
+        }).collect::<Vec<_>>();
+
+        let mut lines = motion.lines();
+        let frames = lines.next().unwrap().strip_prefix("Frames: ").unwrap().parse::<usize>().unwrap();
+        assert_eq!(frames, animation.frame_count.ceil() as usize);
+        let lines = lines.skip(1).collect::<Vec<_>>();
+        assert_eq!(lines.len(), frames);
+
+        for (frame, line) in lines.iter().enumerate() {
+            pose.update_joints(frame as f32);
+            let values = line.split(' ').map(|value| value.parse::<f32>().unwrap()).collect::<Vec<_>>();
+            assert_eq!(values.len(), channel_count);
+            let mut values = &values[..];
+            for ((name, channels), bone) in joints.iter().zip(&bones) {
+                let bone = &pose.bones[*bone];
+                let (joint, rest) = values.split_at(channels.len());
+                values = rest;
+                let (translation, angles) = joint.split_at(channels.len() - 3);
+                if !translation.is_empty() {
+                    assert_eq!(translation, [bone.joint.tx, bone.joint.ty, bone.joint.tz]);
+                }
+                let actual = rotation(&channels[channels.len() - 3..], angles);
+                assert!(actual.angle_to(&bone.local_rotation()) < 1e-3, "{:?}: bone {} at frame {}", order, name, frame);
+            }
+        }
+    }
+}
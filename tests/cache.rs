@@ -0,0 +1,73 @@
+use melee_anim_rs::cache::{source_hash, write_cache, Cache, CacheError, VERSION};
+use melee_anim_rs::hurtbox::parse_hurtboxes_from_path;
+
+mod common;
+
+use common::{load, ASSETS};
+
+fn cache() -> (u64, Vec<u8>) {
+    let mut animation = load();
+    animation.hurtboxes = parse_hurtboxes_from_path(&format!("{}hurtboxes.csv", ASSETS)).unwrap();
+    let hash = source_hash(&[b"model", b"animation"]);
+    let mut data = vec![];
+    write_cache(&mut data, hash, &animation.model, &animation.hurtboxes, &[("wait", &animation)]).unwrap();
+    (hash, data)
+}
+
+#[test]
+fn animations_round_trip() {
+    let expected = load();
+    let (hash, data) = cache();
+    let cache = Cache::read(&data).unwrap();
+    assert_eq!(cache.source_hash(), hash);
+    assert!(cache.find("walk").is_none());
+    let cached = cache.find("wait").unwrap();
+    assert_eq!(cached.frame_count, expected.frame_count);
+
+    let animation = cache.animation(cached).unwrap();
+    assert_eq!(animation.hurtboxes.len(), parse_hurtboxes_from_path(&format!("{}hurtboxes.csv", ASSETS)).unwrap().len());
+    for (bone, other) in expected.model.bones.iter().zip(&animation.model.bones) {
+        assert_eq!((bone.index, bone.parent, &bone.name, &bone.childs), (other.index, other.parent, &other.name, &other.childs));
+        assert_eq!(bone.tracks.len(), other.tracks.len());
+        for (track, other) in bone.tracks.iter().zip(&other.tracks) {
+            assert_eq!((track.r#type, &track.keys), (other.r#type, &other.keys));
+        }
+    }
+
+    // Tracks can be walked without building the animation
+    let tracks = cached.tracks().collect::<Result<Vec<_>, _>>().unwrap();
+    let key_count = expected.model.bones.iter().flat_map(|bone| &bone.tracks).map(|track| track.keys.len()).sum::<usize>();
+    assert_eq!(tracks.iter().map(|track| track.key_count()).sum::<usize>(), key_count);
+}
+
+#[test]
+fn damaged_caches_are_rejected() {
+    let (_, data) = cache();
+
+    let mut corrupted = data.clone();
+    let last = corrupted.len() - 1;
+    corrupted[last] ^= 1;
+    assert_eq!(Cache::read(&corrupted).err(), Some(CacheError::ChecksumMismatch));
+
+    for len in 0..data.len() {
+        assert_eq!(Cache::read(&data[..len]).err(), Some(CacheError::Truncated), "{} bytes", len);
+    }
+
+    let mut magic = data.clone();
+    magic[0] = b'X';
+    assert_eq!(Cache::read(&magic).err(), Some(CacheError::BadMagic));
+
+    let mut version = data;
+    version[4..8].copy_from_slice(&(VERSION + 1).to_le_bytes());
+    assert_eq!(Cache::read(&version).err(), Some(CacheError::UnsupportedVersion(VERSION + 1)));
+}
+
+#[test]
+fn stale_caches_are_detected() {
+    let (hash, data) = cache();
+    let cache = Cache::read(&data).unwrap();
+    assert!(cache.is_fresh(hash));
+    assert!(!cache.is_fresh(source_hash(&[b"model", b"other animation"])));
+    // Moving bytes between sources changes the hash
+    assert_ne!(source_hash(&[b"ab", b"c"]), source_hash(&[b"a", b"bc"]));
+}
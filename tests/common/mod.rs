@@ -0,0 +1,23 @@
+// Fixtures shared by the integration tests. Each test file only uses some of them.
+#![allow(dead_code)]
+
+use melee_anim_rs::animation::Animation;
+use melee_anim_rs::Source;
+
+pub const ASSETS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/assets/");
+
+// The bundled model with its animation, without hurtboxes.
+pub fn load() -> Animation {
+    let mut animation = Animation::from_smd_path(&format!("{}model.smd", ASSETS)).unwrap();
+    animation.load_figatree_from_path(&format!("{}animation.figatree", ASSETS)).unwrap();
+    animation
+}
+
+// The bundled model, hurtboxes and animation.
+pub fn load_with_hurtboxes() -> Animation {
+    melee_anim_rs::get_animation_with_hurtboxes(
+        Source::Path(&format!("{}model.smd", ASSETS)),
+        Source::Path(&format!("{}hurtboxes.csv", ASSETS)),
+        Source::Path(&format!("{}animation.figatree", ASSETS)),
+    ).unwrap()
+}
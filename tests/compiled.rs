@@ -0,0 +1,25 @@
+
+mod common;
+
+use common::load;
+
+#[test]
+fn compiled_joints_are_bit_identical() {
+    let animation = load();
+    let compiled = animation.compile();
+    let mut lanes = compiled.lanes();
+    let mut model = animation.model.clone();
+    let mut joints = animation.model.bones.iter().map(|bone| bone.joint).collect::<Vec<_>>();
+
+    let quarters = (animation.frame_count * 4.).ceil() as usize + 4;
+    for quarter in 0..=quarters {
+        let frame = quarter as f32 / 4. - 0.5;
+        model.update_joints(frame);
+        compiled.evaluate(frame, &mut lanes, &mut joints);
+        for (bone, joint) in model.bones.iter().zip(&joints) {
+            let expected = [bone.joint.tx, bone.joint.ty, bone.joint.tz, bone.joint.rx, bone.joint.ry, bone.joint.rz];
+            let actual = [joint.tx, joint.ty, joint.tz, joint.rx, joint.ry, joint.rz];
+            assert_eq!(expected.map(f32::to_bits), actual.map(f32::to_bits), "bone {} at frame {}", bone.index, frame);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use melee_anim_rs::animation::{InterpolationType, Key, Track, TrackType};
+use melee_anim_rs::curve::{Mode, Rule, Tangent};
+
+fn track() -> Track {
+    Track {r#type: TrackType::HSD_A_J_TRAY, keys: vec![
+        Key::new(5., 2., 0., InterpolationType::HSD_A_OP_LIN),
+        Key::new(10., 4., 0., InterpolationType::HSD_A_OP_SPL),
+        Key::new(10., 0., 1., InterpolationType::HSD_A_OP_SLP),
+        Key::new(20., 1., -1., InterpolationType::HSD_A_OP_SPL),
+    ]}
+}
+
+#[test]
+fn breakpoints_are_sorted_and_unique() {
+    assert_eq!(track().breakpoints(), [0., 5., 10., 20.]);
+}
+
+#[test]
+fn segments_cover_every_frame() {
+    let track = track();
+    let segments = track.segments();
+    assert_eq!(segments.len(), 5);
+    assert_eq!(segments[0].start, f32::NEG_INFINITY);
+    assert_eq!(segments[4].end, f32::INFINITY);
+    for pair in segments.windows(2) {
+        assert_eq!(pair[0].end, pair[1].start);
+    }
+    for step in -20..100 {
+        let frame = step as f32 / 4.;
+        let segment = segments.iter().find(|s| s.contains(frame)).unwrap();
+        assert_eq!(segment.value(frame), track.get_value(frame), "frame {}", frame);
+    }
+    assert_eq!(segments[4].end_key, None);
+    assert_eq!(segments[4].mode(), Mode::Constant);
+
+    // The SLP key at frame 10 gives the spline towards frame 20 its start slope
+    let spline = &segments[3];
+    assert_eq!((spline.start, spline.end, spline.end_key), (10., 20., Some(3)));
+    assert_eq!(spline.mode(), Mode::Hermite);
+    let slope = spline.slope.unwrap();
+    assert_eq!((slope.key, slope.tangent), (2, Tangent::Outgoing));
+    assert!(segments.iter().filter(|s| s.slope.is_some()).count() == 1);
+}
+
+#[test]
+fn explain_names_the_rule() {
+    let track = track();
+    for step in -20..100 {
+        let frame = step as f32 / 4.;
+        let explanation = track.explain(frame);
+        assert_eq!(explanation.value, track.get_value(frame), "frame {}", frame);
+        assert!(explanation.segment.contains(frame));
+    }
+
+    let explanation = track.explain(15.);
+    assert_eq!(explanation.rule, Rule::Hermite {time: 5., length: 10.});
+    let text = explanation.to_string();
+    assert!(text.contains("segment [10, 20) from 10 to 20, hermite from 4 (slope 1) to 1 (slope -1) at 5 of 10 frames"), "{}", text);
+    assert!(text.ends_with(", start slope from SLP key 2"), "{}", text);
+
+    assert_eq!(track.explain(25.).rule, Rule::Held);
+    assert_eq!(track.explain(10.).rule, Rule::AtStart);
+}
+
+#[test]
+fn sample_steps_up_to_the_end() {
+    let track = track();
+    let samples = track.sample(0., 20., 2.5);
+    assert_eq!(samples.len(), 9);
+    for (frame, value) in samples {
+        assert_eq!(value, track.get_value(frame));
+    }
+    assert_eq!(track.sample(3., 3., 1.), [(3., track.get_value(3.))]);
+    assert!(track.sample(4., 3., 1.).is_empty());
+}
+
+#[test]
+fn sample_rejects_unbounded_ranges() {
+    let track = track();
+    assert!(track.sample(f32::NAN, 10., 1.).is_empty());
+    assert!(track.sample(0., f32::NAN, 1.).is_empty());
+    assert!(track.sample(0., f32::INFINITY, 1.).is_empty());
+    assert!(track.sample(f32::NEG_INFINITY, 10., 1.).is_empty());
+    assert!(track.sample(0., 10., 0.).is_empty());
+    assert!(track.sample(0., 10., -1.).is_empty());
+    assert!(track.sample(0., 10., f32::NAN).is_empty());
+}
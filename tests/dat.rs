@@ -0,0 +1,33 @@
+use melee_anim_rs::animation::{Key, InterpolationType, Track, TrackType};
+use melee_anim_rs::dat::{encode_track, replace_animation, ActionEntry, EncodeFigaTreeError, PatchAnimationError};
+use melee_anim_rs::error::Error;
+
+mod common;
+
+use common::load;
+
+#[test]
+fn empty_tracks_are_left_out() {
+    let mut animation = load();
+    let encoded = animation.encode_figatree(0.001).unwrap();
+    animation.model.bones[0].tracks.push(Track {r#type: TrackType::HSD_A_J_SCAX, keys: vec![]});
+    assert_eq!(animation.encode_figatree(0.001).unwrap().data, encoded.data);
+    assert_eq!(encode_track(animation.model.bones[0].tracks.last().unwrap(), 0.001).unwrap_err(), EncodeFigaTreeError::EmptyTrack(TrackType::HSD_A_J_SCAX));
+}
+
+#[test]
+fn errors_say_what_went_wrong() {
+    let track = Track {r#type: TrackType::HSD_A_J_ROTX, keys: vec![
+        Key::new(0., 0., 0., InterpolationType::HSD_A_OP_LIN),
+        Key::new(1.5, 1., 0., InterpolationType::HSD_A_OP_LIN),
+    ]};
+    let error = encode_track(&track, 0.001).unwrap_err();
+    assert_eq!(error, EncodeFigaTreeError::NonIntegerFrame(1.5));
+
+    let error = Error::from(error);
+    assert!(error.to_string().contains("1.5"));
+
+    let mut table = vec![ActionEntry {animation_offset: 0, animation_size: 0x40}];
+    assert_eq!(replace_animation(&[0; 0x20], &mut table, 0, &[]).unwrap_err(), PatchAnimationError::OffsetOutOfRange {offset: 0x40, len: 0x20});
+    assert_eq!(replace_animation(&[0; 0x20], &mut table, 1, &[]).unwrap_err(), PatchAnimationError::UnknownAction(1));
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use melee_anim_rs::animation::{InterpolationType, Key, Track, TrackType};
+use melee_anim_rs::bone::Bone;
+use melee_anim_rs::edit::EditError;
+
+use common::load;
+
+fn key(frame: f32, value: f32) -> Key {
+    Key::new(frame, value, 0., InterpolationType::HSD_A_OP_LIN)
+}
+
+fn frames(track: &Track) -> Vec<(f32, f32)> {
+    track.keys.iter().map(|key| (key.frame(), key.value())).collect()
+}
+
+#[test]
+fn inserted_keys_go_after_keys_at_their_frame() {
+    let mut track = Track::new(TrackType::HSD_A_J_TRAX);
+    assert_eq!(track.insert_key(key(10., 1.)), Ok(0));
+    assert_eq!(track.insert_key(key(0., 2.)), Ok(0));
+    assert_eq!(track.insert_key(key(10., 3.)), Ok(2));
+    assert_eq!(track.insert_key(key(5., 4.)), Ok(1));
+    assert_eq!(frames(&track), [(0., 2.), (5., 4.), (10., 1.), (10., 3.)]);
+    assert!(track.is_sorted());
+
+    for frame in [-1., f32::NAN, f32::INFINITY] {
+        assert!(matches!(track.insert_key(key(frame, 0.)), Err(EditError::InvalidFrame(_))));
+    }
+    assert_eq!(track.keys.len(), 4);
+}
+
+#[test]
+fn removed_keys_keep_the_others_in_order() {
+    let mut track = Track::new(TrackType::HSD_A_J_TRAX);
+    track.keys = vec![key(0., 0.), key(5., 1.), key(10., 2.)];
+    assert_eq!(track.remove_key(1), Ok(key(5., 1.)));
+    assert_eq!(frames(&track), [(0., 0.), (10., 2.)]);
+    assert_eq!(track.remove_key(2), Err(EditError::KeyIndex(2)));
+    assert_eq!(track.keys.len(), 2);
+}
+
+#[test]
+fn moved_keys_are_reinserted_in_order() {
+    let mut track = Track::new(TrackType::HSD_A_J_TRAX);
+    track.keys = vec![key(0., 0.), key(5., 1.), key(10., 2.)];
+    assert_eq!(track.move_key(0, 7.), Ok(1));
+    assert_eq!(frames(&track), [(5., 1.), (7., 0.), (10., 2.)]);
+    assert_eq!(track.move_key(2, 5.), Ok(1));
+    assert_eq!(frames(&track), [(5., 1.), (5., 2.), (7., 0.)]);
+    assert_eq!(track.move_key(1, 5.), Ok(1));
+    assert_eq!(frames(&track), [(5., 1.), (5., 2.), (7., 0.)]);
+
+    // Failed moves leave the track alone
+    assert_eq!(track.move_key(0, -1.), Err(EditError::InvalidFrame(-1.)));
+    assert_eq!(track.move_key(3, 1.), Err(EditError::KeyIndex(3)));
+    assert_eq!(frames(&track), [(5., 1.), (5., 2.), (7., 0.)]);
+}
+
+#[test]
+fn bone_tracks_are_replaced_by_type() {
+    let mut bone = Bone::new(0, -1, "root".to_string());
+    let mut first = Track::new(TrackType::HSD_A_J_ROTX);
+    first.keys.push(key(0., 1.));
+    assert!(bone.set_track(first).is_none());
+    assert!(bone.set_track(Track::new(TrackType::HSD_A_J_ROTY)).is_none());
+    let replaced = bone.set_track(Track::new(TrackType::HSD_A_J_ROTX)).unwrap();
+    assert_eq!(replaced.keys, [key(0., 1.)]);
+    assert_eq!(bone.tracks.len(), 2);
+    assert!(bone.track(TrackType::HSD_A_J_ROTX).unwrap().keys.is_empty());
+
+    assert!(bone.remove_track(TrackType::HSD_A_J_ROTY).is_some());
+    assert!(bone.remove_track(TrackType::HSD_A_J_ROTY).is_none());
+    assert!(bone.track(TrackType::HSD_A_J_ROTY).is_none());
+}
+
+#[test]
+fn animation_edits_find_their_track() {
+    let mut animation = load();
+    let bone_index = animation.model.bones[1].index;
+    assert!(matches!(animation.remove_key(-5, TrackType::HSD_A_J_TRAX, 0), Err(EditError::UnknownBone(-5))));
+    assert!(matches!(animation.remove_key(bone_index, TrackType::HSD_A_J_SCAX, 0), Err(EditError::MissingTrack(TrackType::HSD_A_J_SCAX))));
+
+    // Inserting creates the track
+    assert_eq!(animation.insert_key(bone_index, TrackType::HSD_A_J_SCAX, key(4., 2.)), Ok(0));
+    assert_eq!(animation.insert_key(bone_index, TrackType::HSD_A_J_SCAX, key(2., 1.)), Ok(0));
+    animation.set_value(bone_index, TrackType::HSD_A_J_SCAX, 1, 3.).unwrap();
+    animation.set_tan(bone_index, TrackType::HSD_A_J_SCAX, 1, 0.5).unwrap();
+    animation.set_interpolation_type(bone_index, TrackType::HSD_A_J_SCAX, 1, InterpolationType::HSD_A_OP_SPL).unwrap();
+    assert_eq!(animation.move_key(bone_index, TrackType::HSD_A_J_SCAX, 1, 1.), Ok(0));
+    let bone = animation.bone_mut(bone_index).unwrap();
+    assert_eq!(bone.track(TrackType::HSD_A_J_SCAX).unwrap().keys, [Key::new(1., 3., 0.5, InterpolationType::HSD_A_OP_SPL), key(2., 1.)]);
+
+    let removed = animation.remove_track(bone_index, TrackType::HSD_A_J_SCAX).unwrap();
+    assert_eq!(removed.keys.len(), 2);
+    assert!(matches!(animation.remove_track(bone_index, TrackType::HSD_A_J_SCAX), Err(EditError::MissingTrack(_))));
+}
+
+#[test]
+fn frame_count_only_grows() {
+    let mut animation = load();
+    let bone_index = animation.model.bones[1].index;
+    let frame_count = animation.frame_count;
+    let last_key_frame = animation.last_key_frame();
+    assert!(last_key_frame <= frame_count);
+
+    animation.insert_key(bone_index, TrackType::HSD_A_J_SCAX, key(frame_count + 20., 1.)).unwrap();
+    assert_eq!(animation.frame_count, frame_count + 20.);
+    assert_eq!(animation.last_key_frame(), frame_count + 20.);
+    animation.move_key(bone_index, TrackType::HSD_A_J_SCAX, 0, frame_count + 30.).unwrap();
+    assert_eq!(animation.frame_count, frame_count + 30.);
+
+    // Moving the key back or removing it keeps the frame count
+    animation.move_key(bone_index, TrackType::HSD_A_J_SCAX, 0, 1.).unwrap();
+    assert_eq!(animation.frame_count, frame_count + 30.);
+    animation.remove_key(bone_index, TrackType::HSD_A_J_SCAX, 0).unwrap();
+    assert_eq!(animation.frame_count, frame_count + 30.);
+    assert_eq!(animation.last_key_frame(), last_key_frame);
+
+    let mut track = Track::new(TrackType::HSD_A_J_SCAY);
+    track.keys = vec![key(frame_count + 40., 0.), key(3., 0.)];
+    assert!(animation.set_track(bone_index, track).unwrap().is_none());
+    assert_eq!(animation.frame_count, frame_count + 40.);
+    assert!(animation.bone_mut(bone_index).unwrap().track(TrackType::HSD_A_J_SCAY).unwrap().is_sorted());
+}
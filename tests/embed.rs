@@ -0,0 +1,53 @@
+mod common;
+
+use melee_anim_rs::animation::Animation;
+use melee_anim_rs::embed::write_static_animation;
+
+use common::load_with_hurtboxes;
+
+include!("../examples/assets/animation.rs");
+
+#[test]
+fn generated_file_is_up_to_date() {
+    let mut generated = Vec::new();
+    write_static_animation(&mut generated, "ANIMATION", &load_with_hurtboxes()).unwrap();
+    let checked_in = include_str!("../examples/assets/animation.rs");
+    assert!(String::from_utf8(generated).unwrap() == checked_in, "run `cargo run --example embed` to regenerate examples/assets/animation.rs");
+}
+
+#[test]
+fn static_poses_match_the_parsed_animation() {
+    let parsed = load_with_hurtboxes();
+    let mut parsed_scratch = parsed.model.clone();
+    let mut static_scratch = ANIMATION.model();
+    let (mut expected, mut actual) = (Vec::new(), Vec::new());
+    assert_eq!(ANIMATION.frame_count, parsed.frame_count);
+    for step in 0..=(parsed.frame_count as usize * 2) {
+        let frame = step as f32 / 2.;
+        parsed.pose_into(frame, &mut parsed_scratch);
+        ANIMATION.pose_into(frame, &mut static_scratch);
+        for (expected, actual) in parsed_scratch.bones.iter().zip(&static_scratch.bones) {
+            assert_eq!(expected.index, actual.index);
+            assert_eq!(expected.transform(), actual.transform(), "bone {} at frame {}", expected.index, frame);
+        }
+        parsed.query_hurtboxes_into(frame, &mut parsed_scratch, &mut expected);
+        ANIMATION.query_hurtboxes_into(frame, &mut static_scratch, &mut actual);
+        assert_eq!(expected, actual, "frame {}", frame);
+    }
+}
+
+#[test]
+fn from_static_copies_the_parsed_animation() {
+    let parsed = load_with_hurtboxes();
+    let copied = Animation::from_static(&ANIMATION);
+    assert_eq!(copied.frame_count, parsed.frame_count);
+    assert_eq!(format!("{:?}", copied.hurtboxes), format!("{:?}", parsed.hurtboxes));
+    for (expected, actual) in parsed.model.bones.iter().zip(&copied.model.bones) {
+        assert_eq!((expected.index, expected.parent, &expected.name), (actual.index, actual.parent, &actual.name));
+        assert_eq!(expected.tracks.len(), actual.tracks.len());
+        for (expected, actual) in expected.tracks.iter().zip(&actual.tracks) {
+            assert_eq!(expected.r#type, actual.r#type);
+            assert_eq!(expected.keys, actual.keys);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use melee_anim_rs::animation::Animation;
+use melee_anim_rs::bone::Model;
+use melee_anim_rs::error::{Cause, Error, Input};
+
+mod common;
+
+use common::ASSETS;
+
+const FIGATREE: &str = "FrameCount: 10
+Node 0:
+HSD_A_J_ROTX
+{
+\t0 0 0 HSD_A_OP_LIN
+\t5 1.5x 0 HSD_A_OP_LIN
+}
+";
+
+#[test]
+fn parse_errors_point_at_the_token() {
+    let mut animation = Animation::from_smd_path(&format!("{}model.smd", ASSETS)).unwrap();
+    let error = animation.load_figatree_from_bytes(FIGATREE.as_bytes()).unwrap_err();
+    assert_eq!(error.input(), Input::Animation);
+    assert_eq!((error.line(), error.column()), (Some(6), Some(4)));
+    assert!(matches!(&error, Error::Parse {token, cause: Cause::Float(_), ..} if token == "1.5x"));
+    assert!(error.to_string().starts_with("animation data, line 6, column 4: `1.5x`"), "{}", error);
+
+    let smd = "version 1\nnodes\n0 \"root\" -1\nend\nskeleton\ntime 0\n0 0 0 0 0 0 zero\nend\n";
+    let error = Model::from_smd_bytes(smd.as_bytes()).unwrap_err();
+    assert_eq!((error.input(), error.line()), (Input::Model, Some(7)));
+    assert!(matches!(&error, Error::Parse {token, cause: Cause::Float(_), ..} if token == "zero"));
+}
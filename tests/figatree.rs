@@ -0,0 +1,42 @@
+use melee_anim_rs::animation::Animation;
+
+mod common;
+
+use common::{load, ASSETS};
+
+#[test]
+fn round_trip_evaluates_identically() {
+    let animation = load();
+    let mut text = vec![];
+    animation.write_figatree(&mut text).unwrap();
+
+    let mut reloaded = Animation::from_smd_path(&format!("{}model.smd", ASSETS)).unwrap();
+    reloaded.load_figatree_from_bytes(&text).unwrap();
+
+    assert_eq!(animation.frame_count.to_bits(), reloaded.frame_count.to_bits());
+    for (bone, other) in animation.model.bones.iter().zip(&reloaded.model.bones) {
+        assert_eq!(bone.tracks.len(), other.tracks.len());
+        for (track, other) in bone.tracks.iter().zip(&other.tracks) {
+            assert_eq!(track.keys, other.keys);
+            for step in 0..=animation.frame_count as usize * 4 {
+                let frame = step as f32 / 4.;
+                assert_eq!(track.get_value(frame).to_bits(), other.get_value(frame).to_bits(), "bone {} frame {}", bone.index, frame);
+            }
+        }
+    }
+}
+
+#[test]
+fn loading_with_hurtboxes_reports_diagnostics() {
+    use melee_anim_rs::figatree::Diagnostic;
+    use melee_anim_rs::{get_animation_with_diagnostics, Source};
+
+    let (_, diagnostics) = get_animation_with_diagnostics(
+        Source::Path(&format!("{}model.smd", ASSETS)),
+        Source::Path(&format!("{}hurtboxes.csv", ASSETS)),
+        Source::Path(&format!("{}animation.figatree", ASSETS)),
+    ).unwrap();
+    assert_eq!(diagnostics.iter().filter(|d| matches!(d, Diagnostic::StraySlope {..})).count(), 1);
+    assert_eq!(diagnostics.iter().filter(|d| matches!(d, Diagnostic::KeyClamped {frame, ..} if *frame == 7414.)).count(), 8);
+    assert_eq!(diagnostics.len(), 9);
+}
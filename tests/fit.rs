@@ -0,0 +1,46 @@
+use melee_anim_rs::animation::{Track, TrackType};
+use melee_anim_rs::fit::{fit_keys, FitError};
+
+fn samples() -> Vec<f32> {
+    (0..40).map(|frame| (frame as f32 * 0.3).sin() + if frame % 7 == 0 { 0.05 } else { 0. }).collect()
+}
+
+// Values with no structure, which no fit reproduces exactly with fewer keys than frames
+fn noise() -> Vec<f32> {
+    let mut state = 12345u32;
+    (0..60).map(|_| {
+        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+        (state >> 8) as f32 / (1 << 24) as f32 * 100. - 50.
+    }).collect()
+}
+
+fn within(track: &Track, values: &[f32], tolerance: f32) -> bool {
+    values.iter().enumerate().all(|(frame, value)| (track.get_value(frame as f32) - value).abs() <= tolerance)
+}
+
+#[test]
+fn fitted_tracks_stay_within_tolerance() {
+    // Splines through the last values overflow
+    let huge = vec![0., 3e38, -3e38, 0., 1e38, 3.4e38, -3.4e38];
+    for values in [samples(), noise(), huge, vec![3.], vec![1., 1e7, -1e-7, 0.1]] {
+        for tolerance in [0., 1e-6, 1e-3, 0.1, 10.] {
+            let track = Track::from_samples(TrackType::HSD_A_J_ROTX, &values, tolerance).unwrap();
+            assert!(track.keys.len() <= values.len() + 2);
+            assert!(within(&track, &values, tolerance), "{:?} with tolerance {}", values, tolerance);
+        }
+    }
+    assert_eq!(fit_keys(&[], 0.1), Ok(vec![]));
+}
+
+#[test]
+fn invalid_input_is_rejected() {
+    let values = samples();
+    assert_eq!(fit_keys(&values, -0.1), Err(FitError::InvalidTolerance(-0.1)));
+    assert!(matches!(fit_keys(&values, f32::NAN), Err(FitError::InvalidTolerance(tolerance)) if tolerance.is_nan()));
+
+    let mut broken = values.clone();
+    broken[5] = f32::INFINITY;
+    assert_eq!(fit_keys(&broken, 0.1), Err(FitError::NonFiniteSample {frame: 5, value: f32::INFINITY}));
+    broken[3] = f32::NAN;
+    assert!(matches!(Track::from_samples(TrackType::HSD_A_J_ROTX, &broken, 0.1), Err(FitError::NonFiniteSample {frame: 3, ..})));
+}
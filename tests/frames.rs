@@ -0,0 +1,59 @@
+mod common;
+
+use common::{load, load_with_hurtboxes};
+
+#[test]
+fn frames_match_get_frame_model() {
+    let animation = load_with_hurtboxes();
+    let mut frames = animation.frames();
+    let mut count = 0;
+    while let Some(frame) = frames.next() {
+        assert_eq!(frame.frame, count);
+        let expected = animation.get_frame_model(frame.frame as f32);
+        for (expected, actual) in expected.bones.iter().zip(&frame.model.bones) {
+            assert_eq!(expected.transform(), actual.transform(), "bone {} at frame {}", expected.index, frame.frame);
+        }
+        let hurtboxes_2d = animation.get_frame_hurtboxes_2d(frame.frame as f32);
+        assert_eq!(hurtboxes_2d.len(), frame.hurtboxes_2d.len());
+        for ((e1, e2, es), (a1, a2, size)) in hurtboxes_2d.iter().zip(frame.hurtboxes_2d) {
+            assert_eq!((e1.x, e1.y, e2.x, e2.y, es), (a1.x, a1.y, a2.x, a2.y, size));
+        }
+        count += 1;
+    }
+    assert_eq!(count, animation.frame_count.ceil() as usize);
+    assert!(frames.is_empty());
+}
+
+#[test]
+fn bake_matches_get_frame_model() {
+    let animation = load();
+    let baked = animation.bake();
+    assert_eq!(baked.frame_count, animation.frame_count.ceil() as usize);
+    for frame in 0..baked.frame_count {
+        let expected = animation.get_frame_model(frame as f32);
+        assert_eq!(baked.pose(frame).len(), expected.bones.len());
+        for (bone, transform) in expected.bones.iter().zip(baked.pose(frame)) {
+            assert_eq!(baked.transform(frame, bone.index), Some(&bone.transform()));
+            assert_eq!(*transform, bone.transform());
+            let joint = baked.joint(frame, bone.index).unwrap();
+            assert_eq!((joint.tx, joint.ty, joint.tz, joint.rx, joint.ry, joint.rz),
+                (bone.joint.tx, bone.joint.ty, bone.joint.tz, bone.joint.rx, bone.joint.ry, bone.joint.rz));
+            assert_eq!(baked.sample(frame as f32, bone.index), Some(bone.transform()));
+        }
+    }
+}
+
+#[test]
+fn sample_clamps_to_the_table() {
+    let baked = load().bake();
+    let last = baked.frame_count - 1;
+    for &bone_index in baked.indexes.keys() {
+        let first = baked.transform(0, bone_index).copied();
+        let end = baked.transform(last, bone_index).copied();
+        assert_eq!(baked.sample(-1., bone_index), first);
+        assert_eq!(baked.sample(-100.5, bone_index), first);
+        assert_eq!(baked.sample(last as f32 + 0.5, bone_index), end);
+        assert_eq!(baked.sample(1000., bone_index), end);
+    }
+    assert_eq!(baked.sample(0., -1), None);
+}
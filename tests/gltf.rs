@@ -0,0 +1,121 @@
+use std::io::ErrorKind;
+
+use melee_anim_rs::animation::{Animation, InterpolationType, Key, TrackType};
+use melee_anim_rs::hurtbox::parse_hurtboxes_from_path;
+use serde_json::Value;
+
+mod common;
+
+use common::{load, ASSETS};
+
+// JSON and binary chunks of a GLB file.
+fn chunks(glb: &[u8]) -> (Value, &[u8]) {
+    let word = |offset: usize| u32::from_le_bytes([glb[offset], glb[offset + 1], glb[offset + 2], glb[offset + 3]]) as usize;
+    assert_eq!(&glb[..4], b"glTF");
+    assert_eq!(word(4), 2);
+    assert_eq!(word(8), glb.len());
+    let json_len = word(12);
+    assert_eq!(&glb[16..20], b"JSON");
+    let json = serde_json::from_slice(&glb[20..20 + json_len]).unwrap();
+    let bin = 20 + json_len;
+    assert_eq!(&glb[bin + 4..bin + 8], b"BIN\0");
+    assert_eq!(bin + 8 + word(bin), glb.len());
+    (json, &glb[bin + 8..])
+}
+
+fn floats(json: &Value, bin: &[u8], accessor: &Value) -> Vec<f32> {
+    let view = &json["bufferViews"][accessor["bufferView"].as_u64().unwrap() as usize];
+    let offset = view["byteOffset"].as_u64().unwrap() as usize;
+    let len = view["byteLength"].as_u64().unwrap() as usize;
+    bin[offset..offset + len].chunks(4).map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect()
+}
+
+fn export(animation: &Animation) -> Vec<u8> {
+    let mut glb = vec![];
+    animation.write_glb(&mut glb, "animation").unwrap();
+    glb
+}
+
+#[test]
+fn buffers_fit_their_accessors() {
+    let mut animation = load();
+    animation.hurtboxes = parse_hurtboxes_from_path(&format!("{}hurtboxes.csv", ASSETS)).unwrap();
+    let glb = export(&animation);
+    let (json, bin) = chunks(&glb);
+
+    assert_eq!(json["buffers"][0]["byteLength"].as_u64().unwrap() as usize, bin.len());
+    for view in json["bufferViews"].as_array().unwrap() {
+        assert!(view["byteOffset"].as_u64().unwrap() + view["byteLength"].as_u64().unwrap() <= bin.len() as u64);
+    }
+    for accessor in json["accessors"].as_array().unwrap() {
+        let view = &json["bufferViews"][accessor["bufferView"].as_u64().unwrap() as usize];
+        let components = match accessor["type"].as_str().unwrap() {
+            "SCALAR" => 1,
+            "VEC3" => 3,
+            _ => 4,
+        };
+        let size = if accessor["componentType"] == 5123 { 2 } else { 4 };
+        assert_eq!(accessor["count"].as_u64().unwrap() * components * size, view["byteLength"].as_u64().unwrap());
+    }
+    assert_eq!(json["nodes"].as_array().unwrap().len(), animation.model.bones.len() + animation.hurtboxes.len());
+    assert_eq!(json["meshes"].as_array().unwrap().len(), animation.hurtboxes.len());
+}
+
+#[test]
+fn clips_end_on_the_last_pose() {
+    let animation = load();
+    let glb = export(&animation);
+    let (json, bin) = chunks(&glb);
+
+    // Past their last key, tracks hold its value
+    let mut pose = animation.model.clone();
+    for bone in &mut pose.bones {
+        for track in &bone.tracks {
+            let last = track.keys.iter().filter(|key| key.interpolation_type() != InterpolationType::HSD_A_OP_SLP)
+                .fold(None, |last: Option<&Key>, key| if last.is_none_or(|last| key.frame() > last.frame()) { Some(key) } else { last });
+            let value = last.unwrap().value();
+            match track.r#type {
+                TrackType::HSD_A_J_ROTX => bone.joint.rx = value,
+                TrackType::HSD_A_J_ROTY => bone.joint.ry = value,
+                TrackType::HSD_A_J_ROTZ => bone.joint.rz = value,
+                TrackType::HSD_A_J_TRAX => bone.joint.tx = value,
+                TrackType::HSD_A_J_TRAY => bone.joint.ty = value,
+                TrackType::HSD_A_J_TRAZ => bone.joint.tz = value,
+                _ => (),
+            }
+        }
+    }
+    let gltf_animation = &json["animations"][0];
+    for channel in gltf_animation["channels"].as_array().unwrap() {
+        let sampler = &gltf_animation["samplers"][channel["sampler"].as_u64().unwrap() as usize];
+        if sampler["interpolation"] != "LINEAR" {
+            continue;
+        }
+        let input = floats(&json, bin, &json["accessors"][sampler["input"].as_u64().unwrap() as usize]);
+        assert_eq!(*input.last().unwrap(), animation.frame_count / 60.);
+
+        let bone = &pose.bones[channel["target"]["node"].as_u64().unwrap() as usize];
+        let output = floats(&json, bin, &json["accessors"][sampler["output"].as_u64().unwrap() as usize]);
+        let expected = if channel["target"]["path"] == "translation" {
+            let t = bone.local_translation();
+            vec![t.x, t.y, t.z]
+        } else {
+            let q = bone.local_rotation().into_inner().coords;
+            vec![q.x, q.y, q.z, q.w]
+        };
+        let last = &output[output.len() - expected.len()..];
+        // Rotations may be flipped to follow the previous sample
+        let sign = if expected.iter().zip(last).map(|(a, b)| a * b).sum::<f32>() < 0. { -1. } else { 1. };
+        for (actual, expected) in last.iter().zip(&expected) {
+            assert!((actual - sign * expected).abs() < 1e-5, "{} of {}: {:?} instead of {:?}", channel["target"]["path"], bone.name, last, expected);
+        }
+    }
+}
+
+#[test]
+fn non_finite_values_are_rejected() {
+    let mut animation = load();
+    animation.model.bones[1].joint.tx = f32::NAN;
+    let error = animation.write_glb(&mut vec![], "animation").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use melee_anim_rs::animation::Animation;
+use melee_anim_rs::hurtbox::WorldHurtbox;
+use nalgebra::{Point3, UnitQuaternion, Vector3};
+
+use common::{load, load_with_hurtboxes};
+
+// Half way between the integer frames keys sit on, so each difference stays in one segment.
+const FRAMES: [f32; 5] = [0.5, 7.5, 23.5, 51.5, 96.5];
+const H: f32 = 0.05;
+
+fn assert_close(expected: f32, actual: f32, tolerance: f32, what: &str) {
+    assert!((expected - actual).abs() <= tolerance * (1. + expected.abs()), "{}: expected {}, got {}", what, expected, actual);
+}
+
+fn assert_close_vector(expected: Vector3<f32>, actual: Vector3<f32>, tolerance: f32, what: &str) {
+    assert!((expected - actual).norm() <= tolerance * (1. + expected.norm()), "{}: expected {}, got {}", what, expected, actual);
+}
+
+fn positions(animation: &Animation, frame: f32) -> Vec<Point3<f32>> {
+    animation.get_frame_model(frame).bones.iter().map(|bone| bone.transform() * Point3::origin()).collect()
+}
+
+fn rotations(animation: &Animation, frame: f32) -> Vec<UnitQuaternion<f32>> {
+    animation.get_frame_model(frame).bones.iter().map(|bone| bone.rotation()).collect()
+}
+
+fn hurtboxes(animation: &Animation, frame: f32) -> Vec<WorldHurtbox> {
+    let mut scratch = animation.model.clone();
+    let mut hurtboxes = Vec::new();
+    animation.query_hurtboxes_into(frame, &mut scratch, &mut hurtboxes);
+    hurtboxes
+}
+
+#[test]
+fn track_derivatives_match_central_differences() {
+    let animation = load();
+    for bone in &animation.model.bones {
+        for track in &bone.tracks {
+            for &frame in &FRAMES {
+                let (before, at, after) = (track.get_value(frame - H), track.get_value(frame), track.get_value(frame + H));
+                let (velocity, acceleration) = track.get_anim_state(frame).derivatives(frame);
+                let what = format!("bone {} {:?} at frame {}", bone.index, track.r#type, frame);
+                assert_close((after - before) / (2. * H), velocity, 1e-3, &what);
+                assert_close((after - 2. * at + before) / (H * H), acceleration, 1e-2, &what);
+                assert_eq!(track.get_derivatives(frame), (velocity, acceleration));
+            }
+        }
+    }
+}
+
+#[test]
+fn bone_motions_match_central_differences() {
+    let animation = load();
+    for &frame in &FRAMES {
+        let motions = animation.bone_motions(frame);
+        let (before, at, after) = (positions(&animation, frame - H), positions(&animation, frame), positions(&animation, frame + H));
+        let (rotation_before, rotation_after) = (rotations(&animation, frame - H), rotations(&animation, frame + H));
+        let (motions_before, motions_after) = (animation.bone_motions(frame - H), animation.bone_motions(frame + H));
+        for (i, motion) in motions.iter().enumerate() {
+            let what = format!("bone {} at frame {}", motion.bone_index, frame);
+            assert_eq!(motion.bone_index, animation.model.bones[i].index);
+            assert_close_vector(at[i].coords, motion.position.coords, 1e-6, &what);
+            assert_close_vector((after[i] - before[i]) / (2. * H), motion.velocity, 1e-3, &what);
+            assert_close_vector((after[i].coords - 2. * at[i].coords + before[i].coords) / (H * H), motion.acceleration, 1e-2, &what);
+            let turn = (rotation_after[i] * rotation_before[i].inverse()).scaled_axis();
+            assert_close_vector(turn / (2. * H), motion.angular_velocity, 1e-3, &what);
+            let angular_acceleration = (motions_after[i].angular_velocity - motions_before[i].angular_velocity) / (2. * H);
+            assert_close_vector(angular_acceleration, motion.angular_acceleration, 1e-2, &what);
+        }
+    }
+}
+
+#[test]
+fn hurtbox_motions_match_central_differences() {
+    let animation = load_with_hurtboxes();
+    assert!(!animation.hurtboxes.is_empty());
+    for &frame in &FRAMES {
+        let motions = animation.hurtbox_motions(frame);
+        let (before, at, after) = (hurtboxes(&animation, frame - H), hurtboxes(&animation, frame), hurtboxes(&animation, frame + H));
+        assert_eq!(motions.len(), at.len());
+        for (i, motion) in motions.iter().enumerate() {
+            let what = format!("hurtbox {} at frame {}", i, frame);
+            assert_eq!(motion.bone_index, at[i].bone_index);
+            for (point, before, at, after) in [(&motion.p1, before[i].p1, at[i].p1, after[i].p1), (&motion.p2, before[i].p2, at[i].p2, after[i].p2)] {
+                assert_close_vector(at.coords, point.position.coords, 1e-5, &what);
+                assert_close_vector((after - before) / (2. * H), point.velocity, 1e-3, &what);
+                assert_close_vector((after.coords - 2. * at.coords + before.coords) / (H * H), point.acceleration, 1e-2, &what);
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use melee_anim_rs::animation::{Animation, InterpolationType, Key, Track, TrackType};
+use melee_anim_rs::bone::{Bone, Model};
+use melee_anim_rs::fit::FitError;
+use melee_anim_rs::retarget::{BoneMap, RetargetError, Rotation};
+
+use common::load;
+
+// Bones as index, parent, name and rest offset along y, the root first.
+fn model(bones: &[(i32, i32, &str, f32)]) -> Model {
+    let bones = bones.iter().map(|(index, parent, name, ty)| {
+        let mut bone = Bone::new(*index, *parent, name.to_string());
+        bone.joint.ty = *ty;
+        bone
+    }).collect::<Vec<_>>();
+    let indexes = Model::make_index(&bones);
+    let root_bone_index = bones[0].index;
+    let mut model = Model {bones, indexes, root_bone_index};
+    model.compute_childs();
+    model
+}
+
+fn source() -> Animation {
+    let mut animation = Animation::from_model(model(&[(0, -1, "root", 0.), (1, 0, "hip", 2.), (2, 1, "knee", 4.)]));
+    animation.frame_count = 10.;
+    animation.model.bones[0].tracks.push(Track {r#type: TrackType::HSD_A_J_TRAX, keys: vec![
+        Key::new(0., 0., 0., InterpolationType::HSD_A_OP_LIN),
+        Key::new(10., 6., 0., InterpolationType::HSD_A_OP_CON),
+    ]});
+    animation.model.bones[1].tracks.push(Track {r#type: TrackType::HSD_A_J_TRAY, keys: vec![
+        Key::new(0., 2., 0.5, InterpolationType::HSD_A_OP_SPL),
+        Key::new(10., 4., -1., InterpolationType::HSD_A_OP_SPL),
+    ]});
+    animation.model.bones[2].tracks.push(Track {r#type: TrackType::HSD_A_J_ROTX, keys: vec![
+        Key::new(0., 0., 0., InterpolationType::HSD_A_OP_LIN),
+        Key::new(10., 1., 0., InterpolationType::HSD_A_OP_CON),
+    ]});
+    animation
+}
+
+fn target() -> Model {
+    model(&[(10, -1, "root", 0.), (11, 10, "hip", 3.), (12, 11, "shin", 2.), (13, 12, "foot", 1.)])
+}
+
+fn track(animation: &Animation, bone_index: i32, r#type: TrackType) -> &Track {
+    let bone = &animation.model.bones[animation.model.indexes[&bone_index]];
+    bone.tracks.iter().find(|track| track.r#type == r#type).unwrap()
+}
+
+#[test]
+fn bone_maps_pair_names() {
+    let source = source();
+    let target = target();
+    let map = BoneMap::by_name(&source.model, &target);
+    assert_eq!(map.pairs().collect::<Vec<_>>(), [(0, 10), (1, 11)]);
+
+    let map = BoneMap::from_names(&source.model, &target, &[("knee", "shin"), ("hip", "hip")]).unwrap();
+    assert_eq!(map.pairs().collect::<Vec<_>>(), [(1, 11), (2, 12)]);
+    assert_eq!(BoneMap::from_names(&source.model, &target, &[("knee", "ankle")]), Err(RetargetError::UnknownBoneName("ankle".to_string())));
+    assert_eq!(BoneMap::from_names(&source.model, &target, &[("toe", "foot")]), Err(RetargetError::UnknownBoneName("toe".to_string())));
+}
+
+#[test]
+fn invalid_maps_are_rejected() {
+    let source = source();
+    let target = target();
+    let mut map = BoneMap::new();
+    map.insert(1, 11);
+    map.insert(2, 11);
+    assert_eq!(source.retarget(&target, &map, Rotation::Copy).err(), Some(RetargetError::DuplicateTarget(11)));
+
+    map.remove(2);
+    map.insert(7, 12);
+    assert_eq!(source.retarget(&target, &map, Rotation::Copy).err(), Some(RetargetError::UnknownSourceBone(7)));
+
+    map.remove(7);
+    map.insert(2, 20);
+    assert_eq!(source.retarget(&target, &map, Rotation::Copy).err(), Some(RetargetError::UnknownTargetBone(20)));
+}
+
+#[test]
+fn translations_scale_with_limb_length() {
+    let source = source();
+    let target = target();
+    let map = BoneMap::from_names(&source.model, &target, &[("root", "root"), ("hip", "hip"), ("knee", "shin")]).unwrap();
+    let retargeted = source.retarget(&target, &map, Rotation::Copy).unwrap();
+    assert_eq!(retargeted.frame_count, source.frame_count);
+    assert!(retargeted.hurtboxes.is_empty());
+
+    // The hip is 3 long instead of 2, and the root, without a length, takes the ratio of the
+    // mapped skeletons, 5 / 6
+    let hip = track(&retargeted, 11, TrackType::HSD_A_J_TRAY);
+    assert_eq!(hip.keys, [Key::new(0., 3., 0.75, InterpolationType::HSD_A_OP_SPL), Key::new(10., 6., -1.5, InterpolationType::HSD_A_OP_SPL)]);
+    let root = track(&retargeted, 10, TrackType::HSD_A_J_TRAX);
+    assert_eq!(root.keys[1].value(), 6. * 5. / 6.);
+
+    // Rotations are copied and unmapped bones are left without tracks
+    assert_eq!(track(&retargeted, 12, TrackType::HSD_A_J_ROTX).keys, track(&source, 2, TrackType::HSD_A_J_ROTX).keys);
+    assert!(retargeted.model.bones[3].tracks.is_empty());
+}
+
+#[test]
+fn retargeting_onto_the_same_model_keeps_the_poses() {
+    let animation = load();
+    let target = animation.model.clone();
+    let map = BoneMap::by_index(&animation.model, &target);
+    assert_eq!(map.len(), animation.model.bones.len());
+
+    let copied = animation.retarget(&target, &map, Rotation::Copy).unwrap();
+    let tolerance = 1e-3;
+    let relative = animation.retarget(&target, &map, Rotation::RestRelative {tolerance}).unwrap();
+    for frame in 0..animation.frame_count as usize {
+        let frame = frame as f32;
+        let expected = animation.get_frame_model(frame);
+        for (bone, copy) in expected.bones.iter().zip(&copied.get_frame_model(frame).bones) {
+            let (a, b) = (&bone.joint, &copy.joint);
+            assert_eq!((a.tx, a.ty, a.tz, a.rx, a.ry, a.rz), (b.tx, b.ty, b.tz, b.rx, b.ry, b.rz), "bone {} at frame {}", bone.index, frame);
+        }
+        for (bone, other) in expected.bones.iter().zip(&relative.get_frame_model(frame).bones) {
+            // Each of the three angles is within tolerance
+            let angle = bone.local_rotation().angle_to(&other.local_rotation());
+            assert!(angle <= 3. * tolerance, "bone {} at frame {} is off by {}", bone.index, frame, angle);
+        }
+    }
+}
+
+#[test]
+fn retargeting_reports_fit_errors() {
+    let animation = load();
+    let target = animation.model.clone();
+    let map = BoneMap::by_index(&animation.model, &target);
+    let error = animation.retarget(&target, &map, Rotation::RestRelative {tolerance: -1.}).err();
+    assert_eq!(error, Some(RetargetError::Fit(FitError::InvalidTolerance(-1.))));
+}
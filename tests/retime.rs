@@ -0,0 +1,88 @@
+use melee_anim_rs::animation::Animation;
+use melee_anim_rs::dat::EncodeFigaTreeError;
+use melee_anim_rs::retime::{FrameMap, RetimeError};
+
+mod common;
+
+use common::load;
+
+// Every track of `retimed` gives at the mapped frame the value `animation` gives at the source
+// frame. Frames are kept off the integer key frames, where steps happen.
+fn assert_retimed(animation: &Animation, retimed: &Animation, map: &FrameMap) {
+    let (first, last) = map.source_range();
+    let mut frame = first.floor() + 0.37;
+    while frame < last {
+        if frame > first {
+            let target = map.map(frame).unwrap();
+            for (bone, other) in animation.model.bones.iter().zip(&retimed.model.bones) {
+                for (track, other) in bone.tracks.iter().zip(&other.tracks) {
+                    let (expected, actual) = (track.get_value(frame), other.get_value(target));
+                    assert!((expected - actual).abs() <= 1e-5 * expected.abs().max(1.),
+                        "{} of bone {} at frame {}: {} instead of {}", track.r#type, bone.index, frame, actual, expected);
+                }
+            }
+        }
+        frame += 0.5;
+    }
+}
+
+#[test]
+fn retimed_animations_play_the_same_values() {
+    let animation = load();
+    let frame_count = animation.frame_count;
+    let maps = [
+        FrameMap::scale(frame_count, 2.).unwrap(),
+        FrameMap::scale(frame_count, 0.75).unwrap(),
+        FrameMap::reverse(frame_count).unwrap(),
+        FrameMap::trim(3., frame_count - 2.).unwrap(),
+        FrameMap::new(vec![(0., 0.), (4., 10.), (frame_count, frame_count + 6.)]).unwrap(),
+    ];
+    for map in &maps {
+        let mut retimed = load();
+        retimed.retime(map);
+        assert_eq!(retimed.frame_count, map.frame_count());
+        assert_retimed(&animation, &retimed, map);
+    }
+}
+
+#[test]
+fn frame_maps_move_frames() {
+    let map = FrameMap::new(vec![(2., 0.), (4., 10.), (8., 12.)]).unwrap();
+    assert_eq!(map.map(1.), None);
+    assert_eq!(map.map(2.), Some(0.));
+    assert_eq!(map.map(3.), Some(5.));
+    assert_eq!(map.map(6.), Some(11.));
+    assert_eq!(map.map(8.), Some(12.));
+    assert_eq!(map.map(9.), None);
+    assert_eq!(map.frame_count(), 12.);
+
+    let reverse = FrameMap::reverse(10.).unwrap();
+    assert!(reverse.is_reversed());
+    assert_eq!(reverse.map(2.5), Some(7.5));
+
+    assert_eq!(FrameMap::new(vec![(0., 0.)]), Err(RetimeError::TooFewAnchors));
+    assert_eq!(FrameMap::new(vec![(0., 1.), (2., 3.)]), Err(RetimeError::TargetStart(1.)));
+    assert_eq!(FrameMap::new(vec![(0., 0.), (2., 3.), (1., 4.)]), Err(RetimeError::InvalidAnchor(1., 4.)));
+}
+
+#[test]
+fn resampled_animations_can_be_encoded() {
+    let mut retimed = load();
+    retimed.scale_time(0.75).unwrap();
+    assert!(matches!(retimed.encode_figatree(0.001), Err(EncodeFigaTreeError::NonIntegerFrame(_))));
+
+    let mut resampled = load();
+    resampled.scale_time(0.75).unwrap();
+    resampled.resample(1e-3).unwrap();
+    assert_eq!(resampled.frame_count, retimed.frame_count.ceil());
+    for (bone, other) in retimed.model.bones.iter().zip(&resampled.model.bones) {
+        for (track, other) in bone.tracks.iter().zip(&other.tracks) {
+            assert!(other.keys.iter().all(|key| key.frame().fract() == 0.));
+            for frame in 0..resampled.frame_count as usize {
+                let (expected, actual) = (track.get_value(frame as f32), other.get_value(frame as f32));
+                assert!((expected - actual).abs() <= 1e-3, "{} of bone {} at frame {}", track.r#type, bone.index, frame);
+            }
+        }
+    }
+    resampled.encode_figatree(0.001).unwrap();
+}
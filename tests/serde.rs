@@ -0,0 +1,32 @@
+#![cfg(feature = "serde")]
+
+use melee_anim_rs::animation::Animation;
+use melee_anim_rs::hurtbox::parse_hurtboxes_from_path;
+
+mod common;
+
+use common::{load, ASSETS};
+
+#[test]
+fn animations_round_trip_through_json() {
+    let mut animation = load();
+    animation.hurtboxes = parse_hurtboxes_from_path(&format!("{}hurtboxes.csv", ASSETS)).unwrap();
+    let json = serde_json::to_string(&animation).unwrap();
+    let reloaded: Animation = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&reloaded).unwrap(), json);
+
+    // Transforms are not serialized, posing computes them again
+    let (mut scratch, mut expected) = (animation.model.clone(), vec![]);
+    let (mut other_scratch, mut actual) = (reloaded.model.clone(), vec![]);
+    let mut frame = 0.;
+    while frame < animation.frame_count {
+        let (model, other_model) = (animation.get_frame_model(frame), reloaded.get_frame_model(frame));
+        for (bone, other) in model.bones.iter().zip(&other_model.bones) {
+            assert_eq!(bone.transform(), other.transform(), "bone {} at frame {}", bone.index, frame);
+        }
+        animation.query_hurtboxes_into(frame, &mut scratch, &mut expected);
+        reloaded.query_hurtboxes_into(frame, &mut other_scratch, &mut actual);
+        assert_eq!(expected, actual);
+        frame += 0.5;
+    }
+}
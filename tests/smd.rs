@@ -0,0 +1,54 @@
+use melee_anim_rs::bone::Model;
+
+mod common;
+
+use common::load;
+
+const SMD: &str = "version 1
+nodes
+0 \"root\" -1
+1 \"arm\" 0
+end
+skeleton
+time 0
+0 0 0 0 0 0 0
+1 1 0 0 0 0 0
+time 1
+0 0 0 0 0 0 0
+1 2 0 0 0 0 0
+end
+";
+
+#[test]
+fn last_time_block_is_the_reference_pose() {
+    let model = Model::from_smd_bytes(SMD.as_bytes()).unwrap();
+    assert_eq!(model.bones[1].joint.tx, 2.);
+
+    let lines = SMD.lines().map(String::from).collect::<Vec<_>>();
+    assert_eq!(Model::from_smd(&lines).unwrap().bones[1].joint.tx, 2.);
+}
+
+#[test]
+fn written_frames_parse_back_as_poses() {
+    let animation = load();
+    let mut text = vec![];
+    animation.write_smd(&mut text).unwrap();
+    let text = String::from_utf8(text).unwrap();
+
+    // Each time block on its own is the reference pose of that frame
+    let (header, blocks) = text.split_once("skeleton\n").unwrap();
+    let blocks = blocks.trim_end().strip_suffix("end").unwrap().split("time ").skip(1).collect::<Vec<_>>();
+    assert_eq!(blocks.len(), animation.frame_count.ceil() as usize);
+
+    let mut pose = animation.model.clone();
+    for (frame, block) in blocks.iter().enumerate() {
+        let model = Model::from_smd_bytes(format!("{}skeleton\ntime {}end\n", header, block).as_bytes()).unwrap();
+        pose.update_joints(frame as f32);
+        assert_eq!(model.bones.len(), pose.bones.len());
+        for (bone, expected) in model.bones.iter().zip(&pose.bones) {
+            assert_eq!((bone.index, bone.parent, &bone.name), (expected.index, expected.parent, &expected.name));
+            let (joint, expected) = (bone.joint, expected.joint);
+            assert_eq!([joint.tx, joint.ty, joint.tz, joint.rx, joint.ry, joint.rz], [expected.tx, expected.ty, expected.tz, expected.rx, expected.ry, expected.rz], "bone {} at frame {}", bone.index, frame);
+        }
+    }
+}
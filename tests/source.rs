@@ -0,0 +1,49 @@
+use std::fs;
+use std::io::BufReader;
+
+use melee_anim_rs::animation::Animation;
+use melee_anim_rs::error::{Error, Input};
+use melee_anim_rs::{get_animation_with_hurtboxes, Source};
+
+mod common;
+
+use common::ASSETS;
+
+fn path(name: &str) -> String {
+    format!("{}{}", ASSETS, name)
+}
+
+fn assert_same(animation: &Animation, other: &Animation) {
+    assert_eq!(animation.frame_count, other.frame_count);
+    assert_eq!(animation.hurtboxes.len(), other.hurtboxes.len());
+    for (bone, other) in animation.model.bones.iter().zip(&other.model.bones) {
+        assert_eq!(bone.tracks.len(), other.tracks.len());
+        for (track, other) in bone.tracks.iter().zip(&other.tracks) {
+            assert_eq!(track.keys, other.keys);
+        }
+    }
+}
+
+#[test]
+fn every_source_loads_the_same_animation() {
+    let (model, hurtboxes, animation) = (path("model.smd"), path("hurtboxes.csv"), path("animation.figatree"));
+    let expected = get_animation_with_hurtboxes(Source::Path(&model), Source::Path(&hurtboxes), Source::Path(&animation)).unwrap();
+    assert!(!expected.hurtboxes.is_empty());
+
+    let (model_text, hurtboxes_text, animation_text) = (fs::read_to_string(&model).unwrap(), fs::read_to_string(&hurtboxes).unwrap(), fs::read_to_string(&animation).unwrap());
+    let text = get_animation_with_hurtboxes(Source::Text(&model_text), Source::Text(&hurtboxes_text), Source::Text(&animation_text)).unwrap();
+    assert_same(&expected, &text);
+
+    let bytes = get_animation_with_hurtboxes(Source::Bytes(model_text.as_bytes()), Source::Bytes(hurtboxes_text.as_bytes()), Source::Bytes(animation_text.as_bytes())).unwrap();
+    assert_same(&expected, &bytes);
+
+    let reader = |path: &str| Source::Reader(Box::new(BufReader::new(fs::File::open(path).unwrap())));
+    let read = get_animation_with_hurtboxes(reader(&model), reader(&hurtboxes), reader(&animation)).unwrap();
+    assert_same(&expected, &read);
+
+    // Text is content, not a file name
+    let error = get_animation_with_hurtboxes(Source::Path("missing.smd"), Source::Text(""), Source::Text("")).err().unwrap();
+    assert!(matches!(error, Error::Io {input: Input::Model, ..}));
+    let empty = get_animation_with_hurtboxes(Source::Text(&model_text), Source::Text(&hurtboxes), Source::Text("")).unwrap();
+    assert!(empty.hurtboxes.is_empty());
+}
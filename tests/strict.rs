@@ -0,0 +1,65 @@
+use melee_anim_rs::animation::Animation;
+use melee_anim_rs::bone::Model;
+use melee_anim_rs::error::{Cause, Error, ParseMode};
+use melee_anim_rs::hurtbox::parse_hurtboxes_from_reader;
+
+mod common;
+
+use common::ASSETS;
+
+const HURTBOXES: &str = "BoneIndex,X1,Y1,Z1,X2,Y2,Z2,Size,Type,Grabable
+4,-1,0,0,1,0,0,2.04,Mid,1
+22,1,0.5,0,-1,1
+22,1,0.5,0,-1,1,0,big,Mid,1
+
+22,1,0.5,0,-1,1,0,2.4,Sideways,1
+22,1,0.5,0,-1,1,0,2.4,Mid,1
+";
+
+const FIGATREE: &str = "FrameCount: 10
+Node 0:
+HSD_A_J_ROTX
+{
+\t0 0 0 HSD_A_OP_LIN
+\t5 1 HSD_A_OP_LIN
+\t8 1 0 HSD_A_OP_WHATEVER
+\t10 0 0 HSD_A_OP_LIN
+}
+";
+
+fn lines(error: &Error) -> Vec<Option<usize>> {
+    match error {
+        Error::Multiple {errors, ..} => errors.iter().map(Error::line).collect(),
+        _ => panic!("expected every error, got {}", error),
+    }
+}
+
+#[test]
+fn strict_mode_reports_every_malformed_line() {
+    let lenient = parse_hurtboxes_from_reader(HURTBOXES.as_bytes(), ParseMode::Lenient).unwrap();
+    assert_eq!(lenient.len(), 2);
+    let error = parse_hurtboxes_from_reader(HURTBOXES.as_bytes(), ParseMode::Strict).unwrap_err();
+    assert_eq!(lines(&error), [Some(3), Some(4), Some(6)]);
+    match &error {
+        Error::Multiple {errors, ..} => assert!(matches!(errors[0], Error::Parse {cause: Cause::FieldCount {expected: 10, found: 6}, ..})),
+        _ => unreachable!(),
+    }
+
+    let mut animation = Animation::from_smd_path(&format!("{}model.smd", ASSETS)).unwrap();
+    let error = animation.load_figatree_from_reader(FIGATREE.as_bytes(), ParseMode::Strict).unwrap_err();
+    assert_eq!(lines(&error), [Some(6), Some(7)]);
+    let error = animation.load_figatree_from_reader(FIGATREE.as_bytes(), ParseMode::Lenient).unwrap_err();
+    assert_eq!(error.line(), Some(6));
+}
+
+#[test]
+fn malformed_skeletons_are_errors() {
+    // Skeleton lines with three fields are not bones
+    let smd = "version 1\nnodes\n0 \"root\" -1\nend\nskeleton\ntime 0\n0 0 0\nend\n";
+    assert_eq!(Model::from_smd_bytes(smd.as_bytes()).unwrap().bones.len(), 1);
+
+    let smd = "version 1\nnodes\n0 \"root\" -1\n0 \"again\" -1\nend\n";
+    let error = Model::from_smd_bytes(smd.as_bytes()).unwrap_err();
+    assert_eq!(error.line(), Some(4));
+    assert!(matches!(error, Error::Parse {cause: Cause::DuplicateBone, ..}));
+}
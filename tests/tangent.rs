@@ -0,0 +1,119 @@
+use melee_anim_rs::animation::{InterpolationType, Key, Track, TrackType};
+use melee_anim_rs::tangent::TangentMode;
+
+#[test]
+fn linear_tangents_add_leaving_slopes() {
+    let mut track = Track {r#type: TrackType::HSD_A_J_TRAY, keys: vec![
+        Key::new(0., 0., 0., InterpolationType::HSD_A_OP_SPL),
+        Key::new(10., 10., 0., InterpolationType::HSD_A_OP_SPL),
+        Key::new(20., 0., 0., InterpolationType::HSD_A_OP_SPL),
+    ]};
+    track.fill_tangents(TangentMode::Linear);
+    let slopes = track.keys.iter().filter(|key| key.interpolation_type() == InterpolationType::HSD_A_OP_SLP).map(|key| key.tan()).collect::<Vec<_>>();
+    assert_eq!(slopes, [-1.]);
+
+    // Every segment is the straight line between its keys
+    for quarter in 0..80 {
+        let frame = quarter as f32 / 4.;
+        let expected = if frame <= 10. { frame } else { 20. - frame };
+        assert!((track.get_value(frame) - expected).abs() < 1e-4, "frame {}", frame);
+    }
+
+    // Filling again reuses the SLP key
+    track.fill_tangents(TangentMode::Linear);
+    assert_eq!(track.keys.len(), 4);
+}
+
+fn spline_track(points: &[(f32, f32)]) -> Track {
+    Track {r#type: TrackType::HSD_A_J_TRAY, keys: points.iter().map(|(frame, value)| Key::new(*frame, *value, 0., InterpolationType::HSD_A_OP_SPL)).collect()}
+}
+
+fn slp_count(track: &Track) -> usize {
+    track.keys.iter().filter(|key| key.interpolation_type() == InterpolationType::HSD_A_OP_SLP).count()
+}
+
+fn tans(track: &Track) -> Vec<f32> {
+    track.keys.iter().map(|key| key.tan()).collect()
+}
+
+#[test]
+fn clamped_tangents_are_flat_at_extrema() {
+    let mut track = spline_track(&[(0., 0.), (10., 10.), (20., 5.), (30., 5.), (40., 20.)]);
+    track.fill_tangents(TangentMode::Clamped);
+    assert_eq!(slp_count(&track), 0);
+    assert_eq!(tans(&track)[1..4], [0., 0., 0.]);
+}
+
+#[test]
+fn clamped_tangents_never_overshoot_monotonic_keys() {
+    let points = [(0., 0.), (10., 1.), (20., 9.), (30., 10.), (40., 30.), (45., 31.)];
+    let mut track = spline_track(&points);
+    track.fill_tangents(TangentMode::Clamped);
+    assert_eq!(slp_count(&track), 0);
+    for pair in points.windows(2) {
+        let ((start, low), (end, high)) = (pair[0], pair[1]);
+        let mut previous = low;
+        for step in 0..=40 {
+            let frame = start + (end - start) * step as f32 / 40.;
+            let value = track.get_value(frame);
+            assert!(value >= previous - 1e-4 && value <= high + 1e-4, "frame {}: {} after {}", frame, value, previous);
+            previous = value;
+        }
+    }
+
+    // Catmull-Rom tangents on the same keys do overshoot
+    let mut track = spline_track(&points);
+    track.fill_tangents(TangentMode::CatmullRom);
+    assert!((0..400).any(|quarter| {
+        let frame = quarter as f32 / 10.;
+        let value = track.get_value(frame);
+        let segment = points.windows(2).find(|pair| pair[0].0 <= frame && frame <= pair[1].0).unwrap();
+        value < segment[0].1 - 1e-4 || value > segment[1].1 + 1e-4
+    }));
+}
+
+#[test]
+fn catmull_rom_tangents_span_the_neighbours() {
+    let mut track = spline_track(&[(0., 0.), (10., 10.), (20., 0.), (40., 10.)]);
+    track.fill_tangents(TangentMode::CatmullRom);
+    assert_eq!(slp_count(&track), 0);
+    // Ends continue the slope of their only segment
+    assert_eq!(tans(&track), [1., 0., 0., 0.5]);
+    for (frame, value) in [(0., 0.), (10., 10.), (20., 0.), (40., 10.)] {
+        assert_eq!(track.get_value(frame), value);
+    }
+}
+
+#[test]
+fn flat_tangents_are_zero() {
+    let mut track = spline_track(&[(0., 0.), (10., 10.), (20., 15.), (30., -5.)]);
+    for key in &mut track.keys {
+        key.set_tan(3.);
+    }
+    track.fill_tangents(TangentMode::Flat);
+    assert_eq!(slp_count(&track), 0);
+    assert_eq!(tans(&track), [0.; 4]);
+}
+
+#[test]
+fn slp_keys_are_only_added_where_slopes_differ() {
+    // On a straight line the leaving slope is the arriving one
+    let mut track = spline_track(&[(0., 0.), (10., 10.), (20., 20.)]);
+    track.fill_tangents(TangentMode::Linear);
+    assert_eq!(slp_count(&track), 0);
+    assert_eq!(tans(&track), [1., 1., 1.]);
+
+    // The kink at frame 10 needs one before the spline key at frame 20. The one at frame 20
+    // gets none, as a spline key followed by a linear key leaves at its arriving slope
+    let mut track = spline_track(&[(0., 0.), (10., 10.), (20., 0.), (30., 0.)]);
+    track.keys[3].set_interpolation_type(InterpolationType::HSD_A_OP_LIN);
+    track.fill_tangents(TangentMode::Linear);
+    let keys = track.keys.iter().map(|key| (key.frame(), key.interpolation_type(), key.tan())).collect::<Vec<_>>();
+    assert_eq!(keys, [
+        (0., InterpolationType::HSD_A_OP_SPL, 1.),
+        (10., InterpolationType::HSD_A_OP_SPL, 1.),
+        (20., InterpolationType::HSD_A_OP_SLP, -1.),
+        (20., InterpolationType::HSD_A_OP_SPL, -1.),
+        (30., InterpolationType::HSD_A_OP_LIN, 0.),
+    ]);
+}